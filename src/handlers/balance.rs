@@ -7,11 +7,13 @@ use tracing::{warn, info, debug};
 use crate::wallet::*;
 use crate::types::*;
 use crate::utils::*;
+use crate::handlers::check_address_inputs;
 
 pub async fn get_native_balance(
     Json(payload): Json<BalanceRequest>,
 ) -> Result<ResponseJson<BalanceResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
     info!("Native balance request: address={}, network={:?}", payload.address, payload.network);
+    check_address_inputs([payload.address.as_str()])?;
     
     let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
     debug!("Using RPC URL: {}", rpc_url);
@@ -46,6 +48,7 @@ pub async fn get_erc20_balance(
 ) -> Result<ResponseJson<BalanceResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
     info!("ERC20 balance request: address={}, token={}, network={:?}", 
           payload.address, payload.token_address, payload.network);
+    check_address_inputs([payload.address.as_str(), payload.token_address.as_str()])?;
     
     let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
     debug!("Using RPC URL: {}", rpc_url);
@@ -3,14 +3,24 @@ use axum::{
     http::StatusCode,
     response::Json as ResponseJson,
 };
-use tracing::warn;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tracing::{info, warn};
 use crate::wallet::*;
+use crate::watch_only::*;
+use crate::vanity::*;
+use crate::handlers::vault::{resolve_wallet, vault_or_error, VaultAuth};
+use crate::handlers::wallet_response;
 use crate::types::*;
+use crate::secret::SecretString;
+use crate::utils::{configured_slip39_max_iteration_exponent, parse_mnemonic_language};
+use bip39::Language;
 
 pub async fn address_from_private_key(
     Json(payload): Json<CreateFromPrivateKeyRequest>,
 ) -> Result<ResponseJson<serde_json::Value>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    match EvmWallet::address_from_private_key(&payload.private_key) {
+    match EvmWallet::address_from_private_key(payload.private_key.expose_secret()) {
         Ok(address) => {
             let response = serde_json::json!({
                 "address": address
@@ -27,6 +37,42 @@ pub async fn address_from_private_key(
     }
 }
 
+pub async fn get_public_key(
+    auth: VaultAuth,
+    Json(payload): Json<PublicKeyRequest>,
+) -> Result<ResponseJson<PublicKeyInfo>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let wallet = resolve_wallet(
+        &auth,
+        payload.private_key.as_ref().map(SecretString::expose_secret),
+        payload.wallet_id.as_deref(),
+    )?;
+    match wallet.public_key_hex().and_then(EvmWallet::public_key_info) {
+        Ok(info) => Ok(ResponseJson(info)),
+        Err(e) => {
+            warn!("Failed to encode public key: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn address_from_public_key(
+    Json(payload): Json<AddressFromPublicKeyRequest>,
+) -> Result<ResponseJson<PublicKeyInfo>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    match EvmWallet::public_key_info(&payload.public_key) {
+        Ok(info) => Ok(ResponseJson(info)),
+        Err(e) => {
+            warn!("Failed to derive address from public key: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
 pub async fn generate_mnemonic() -> Result<ResponseJson<MnemonicResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
     match EvmWallet::generate_mnemonic() {
         Ok(mnemonic) => Ok(ResponseJson(MnemonicResponse { mnemonic })),
@@ -44,8 +90,17 @@ pub async fn generate_mnemonic_with_words(
     Json(payload): Json<GenerateMnemonicRequest>,
 ) -> Result<ResponseJson<MnemonicResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
     let word_count = payload.word_count.unwrap_or(24);
+    let language = match payload.language.as_deref() {
+        Some(name) => parse_mnemonic_language(name).map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            )
+        })?,
+        None => Language::English,
+    };
     
-    match EvmWallet::generate_mnemonic_with_words(word_count) {
+    match EvmWallet::generate_mnemonic_in(language, word_count) {
         Ok(mnemonic) => Ok(ResponseJson(MnemonicResponse { mnemonic })),
         Err(e) => {
             warn!("Failed to generate mnemonic: {}", e);
@@ -57,19 +112,62 @@ pub async fn generate_mnemonic_with_words(
     }
 }
 
+pub async fn validate_mnemonic(
+    Json(payload): Json<ValidateMnemonicRequest>,
+) -> Result<ResponseJson<MnemonicValidation>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let language = payload.language.as_deref()
+        .map(parse_mnemonic_language)
+        .transpose()
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            )
+        })?;
+
+    if payload.mnemonic.expose_secret().split_whitespace().count() > MAX_MNEMONIC_WORDS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: format!("Mnemonic must have at most {} words", MAX_MNEMONIC_WORDS) }),
+        ));
+    }
+
+    Ok(ResponseJson(EvmWallet::validate_mnemonic(payload.mnemonic.expose_secret(), language)))
+}
+
 pub async fn create_wallet_from_mnemonic(
     Json(payload): Json<CreateFromMnemonicRequest>,
 ) -> Result<ResponseJson<WalletResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    match EvmWallet::from_mnemonic(&payload.mnemonic) {
-        Ok(wallet) => {
-            let response = WalletResponse {
-                address: wallet.address,
-                private_key: wallet.private_key,
-                public_key: wallet.public_key,
-                mnemonic: wallet.mnemonic,
-            };
-            Ok(ResponseJson(response))
+    let passphrase = payload.passphrase.as_ref().map(SecretString::expose_secret).unwrap_or("");
+    let language = payload.language.as_deref()
+        .map(parse_mnemonic_language)
+        .transpose()
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            )
+        })?;
+    let result = if payload.legacy.unwrap_or(false) {
+        if payload.account.is_some() || payload.index.is_some() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: "account and index cannot be used with legacy derivation".to_string() }),
+            ));
         }
+        EvmWallet::from_mnemonic_legacy_in(language, payload.mnemonic.expose_secret(), passphrase)
+    } else {
+        EvmWallet::from_mnemonic_in(
+            language,
+            payload.mnemonic.expose_secret(),
+            passphrase,
+            payload.account.unwrap_or(0),
+            payload.index.unwrap_or(0),
+        )
+    };
+
+    match result {
+        Ok(wallet) => wallet_response(wallet).map(ResponseJson),
         Err(e) => {
             warn!("Failed to create wallet from mnemonic: {}", e);
             Err((
@@ -78,4 +176,264 @@ pub async fn create_wallet_from_mnemonic(
             ))
         }
     }
-} 
\ No newline at end of file
+} 
+
+pub async fn derive_addresses(
+    Json(payload): Json<DeriveAddressesRequest>,
+) -> Result<ResponseJson<DeriveAddressesResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let passphrase = payload.passphrase.as_ref().map(SecretString::expose_secret).unwrap_or("");
+    let account = payload.account.unwrap_or(0);
+    let start_index = payload.start_index.unwrap_or(0);
+
+    let result = EvmWallet::account_xpub(payload.mnemonic.expose_secret(), passphrase, account).and_then(|xpub| {
+        EvmWallet::derive_addresses(payload.mnemonic.expose_secret(), passphrase, account, start_index, payload.count)
+            .map(|addresses| (xpub, addresses))
+    });
+
+    match result {
+        Ok((xpub, addresses)) => Ok(ResponseJson(DeriveAddressesResponse {
+            account,
+            xpub,
+            addresses,
+        })),
+        Err(e) => {
+            warn!("Failed to derive addresses: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn derive_xpub_addresses(
+    Json(payload): Json<XpubAddressesRequest>,
+) -> Result<ResponseJson<XpubAddressesResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let result = WatchOnlyWallet::from_xpub(&payload.xpub).and_then(|wallet| {
+        wallet.derive_addresses(payload.start_index.unwrap_or(0), payload.count)
+    });
+
+    match result {
+        Ok(addresses) => Ok(ResponseJson(XpubAddressesResponse { addresses })),
+        Err(e) => {
+            warn!("Failed to derive addresses from xpub: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn split_mnemonic_slip39(
+    Json(payload): Json<Slip39SplitRequest>,
+) -> Result<ResponseJson<Slip39SplitResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let language = payload.language.as_deref()
+        .map(parse_mnemonic_language)
+        .transpose()
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            )
+        })?;
+    let (threshold, share_count) = (payload.threshold, payload.share_count);
+    let word_count = payload.word_count.unwrap_or(24);
+
+    // PBKDF2 key stretching is deliberately slow; keep it off the async workers.
+    let result = tokio::task::spawn_blocking(move || {
+        let passphrase = payload.passphrase.as_ref().map(SecretString::expose_secret).unwrap_or("");
+        let mnemonic = match payload.mnemonic {
+            Some(mnemonic) => mnemonic,
+            None => EvmWallet::generate_mnemonic_in(language.unwrap_or(Language::English), word_count)?,
+        };
+        let wallet = EvmWallet::from_mnemonic_in(language, mnemonic.expose_secret(), "", 0, 0)?;
+        let shares = EvmWallet::split_mnemonic_slip39(
+            mnemonic.expose_secret(),
+            language,
+            passphrase,
+            threshold,
+            share_count,
+        )?;
+        Ok::<_, anyhow::Error>((wallet.address, shares))
+    })
+    .await;
+
+    match result {
+        Ok(Ok((address, shares))) => Ok(ResponseJson(Slip39SplitResponse {
+            address,
+            threshold,
+            share_count,
+            shares,
+        })),
+        Ok(Err(e)) => {
+            warn!("Failed to create SLIP-39 shares: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+        Err(e) => {
+            warn!("SLIP-39 split task failed: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: "SLIP-39 split failed".to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn recover_from_slip39_shares(
+    Json(payload): Json<Slip39RecoverRequest>,
+) -> Result<ResponseJson<WalletResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let language = match payload.language.as_deref() {
+        Some(name) => parse_mnemonic_language(name).map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            )
+        })?,
+        None => Language::English,
+    };
+    let max_iteration_exponent = configured_slip39_max_iteration_exponent();
+
+    let result = tokio::task::spawn_blocking(move || {
+        let passphrase = payload.passphrase.as_ref().map(SecretString::expose_secret).unwrap_or("");
+        EvmWallet::from_slip39_shares(&payload.shares, passphrase, language, max_iteration_exponent)
+    })
+    .await;
+
+    match result {
+        Ok(Ok(wallet)) => wallet_response(wallet).map(ResponseJson),
+        Ok(Err(e)) => {
+            warn!("Failed to recover wallet from SLIP-39 shares: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+        Err(e) => {
+            warn!("SLIP-39 recovery task failed: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: "SLIP-39 recovery failed".to_string() }),
+            ))
+        }
+    }
+}
+
+static VANITY_PERMITS: OnceLock<Arc<Semaphore>> = OnceLock::new();
+
+pub async fn generate_vanity_address(
+    auth: VaultAuth,
+    Json(payload): Json<VanityRequest>,
+) -> Result<ResponseJson<VanityResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let mut options = VanityOptions::new(
+        payload.prefix.as_deref().unwrap_or(""),
+        payload.suffix.as_deref().unwrap_or(""),
+    );
+    if let Some(case_sensitive) = payload.case_sensitive {
+        options.case_sensitive = case_sensitive;
+    }
+    if let Some(threads) = payload.threads {
+        // More threads than CPUs only adds contention, so requests are capped at the machine's parallelism.
+        options.threads = threads.min(available_threads());
+    }
+    if let Some(timeout_secs) = payload.timeout_secs {
+        options.timeout = Duration::from_secs(timeout_secs);
+    }
+    options.validate().map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })?;
+    let vault = if payload.store_in_vault.unwrap_or(false) {
+        let vault = vault_or_error()?;
+        auth.require()?;
+        Some(vault)
+    } else {
+        None
+    };
+    // Busy requests are turned away instead of queued, since a queued search would only start after its client gave up.
+    let permit = VANITY_PERMITS
+        .get_or_init(|| Arc::new(Semaphore::new(crate::utils::configured_max_concurrent_vanity_searches())))
+        .clone()
+        .try_acquire_owned()
+        .map_err(|_| {
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                ResponseJson(ErrorResponse { error: "Another vanity search is already running; try again later".to_string() }),
+            )
+        })?;
+
+    info!(
+        "Starting vanity search: prefix={:?}, suffix={:?}, case_sensitive={}, threads={}, ~{:.0} attempts expected",
+        options.prefix, options.suffix, options.case_sensitive, options.threads, options.expected_attempts()
+    );
+    let result = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        search_vanity_address(&options, |progress| {
+            info!(
+                "Vanity search progress: {} attempts in {:.0}s ({:.0} addr/s, ~{:.0} expected)",
+                progress.attempts,
+                progress.elapsed.as_secs_f64(),
+                progress.rate(),
+                progress.expected_attempts
+            );
+        })
+    })
+    .await;
+
+    let found = match result {
+        Ok(Ok(found)) => found,
+        Ok(Err(e)) => {
+            warn!("Vanity search failed: {}", e);
+            let status = match e {
+                VanityError::Invalid(_) => StatusCode::BAD_REQUEST,
+                VanityError::TimedOut(_) => StatusCode::REQUEST_TIMEOUT,
+                VanityError::Failed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            return Err((
+                status,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ));
+        }
+        Err(e) => {
+            warn!("Vanity search task failed: {}", e);
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ));
+        }
+    };
+    info!("Vanity address found: {} after {} attempts", found.wallet.address, found.attempts);
+
+    let address = found.wallet.address.clone();
+    let elapsed_ms = found.elapsed.as_millis() as u64;
+    match vault {
+        Some(vault) => match vault.store(found.wallet) {
+            Ok(stored) => Ok(ResponseJson(VanityResponse {
+                address,
+                attempts: found.attempts,
+                elapsed_ms,
+                wallet: None,
+                wallet_id: Some(stored.wallet_id),
+            })),
+            Err(e) => {
+                warn!("Failed to store vanity wallet in vault: {}", e);
+                Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    ResponseJson(ErrorResponse { error: e.to_string() }),
+                ))
+            }
+        },
+        None => Ok(ResponseJson(VanityResponse {
+            address,
+            attempts: found.attempts,
+            elapsed_ms,
+            wallet: Some(wallet_response(found.wallet)?),
+            wallet_id: None,
+        })),
+    }
+}
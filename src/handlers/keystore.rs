@@ -0,0 +1,115 @@
+use axum::{
+    extract::Json,
+    http::StatusCode,
+    response::Json as ResponseJson,
+};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
+use crate::keystore::*;
+use crate::wallet::*;
+use crate::types::*;
+use crate::handlers::vault::{resolve_wallet, VaultAuth};
+use crate::handlers::wallet_response;
+use crate::secret::SecretString;
+
+static KDF_PERMITS: OnceLock<Arc<Semaphore>> = OnceLock::new();
+
+// The permit moves into the blocking task, so a dropped request cannot free it while the KDF still runs.
+async fn kdf_permit() -> Result<OwnedSemaphorePermit, (StatusCode, ResponseJson<ErrorResponse>)> {
+    KDF_PERMITS
+        .get_or_init(|| Arc::new(Semaphore::new(crate::utils::configured_max_concurrent_keystore_kdf())))
+        .clone()
+        .acquire_owned()
+        .await
+        .map_err(|e| {
+            warn!("Keystore KDF limiter closed: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: "Keystore KDF is unavailable".to_string() }),
+            )
+        })
+}
+
+pub async fn export_keystore(
+    auth: VaultAuth,
+    Json(payload): Json<KeystoreExportRequest>,
+) -> Result<ResponseJson<KeystoreExportResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let kdf = match payload.kdf.as_deref().map(KeystoreKdf::from_name).transpose() {
+        Ok(kdf) => kdf.unwrap_or_else(KeystoreKdf::scrypt),
+        Err(e) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ));
+        }
+    };
+
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+
+    let address = wallet.address.clone();
+    let permit = kdf_permit().await?;
+    let result = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        wallet.encrypt_keystore(payload.password.expose_secret(), kdf)
+    })
+    .await;
+
+    match result {
+        Ok(Ok(keystore)) => Ok(ResponseJson(KeystoreExportResponse { address, keystore })),
+        Ok(Err(e)) => {
+            warn!("Failed to encrypt keystore: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+        Err(e) => {
+            warn!("Keystore encryption task failed: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: "Keystore encryption failed".to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn import_keystore(
+    Json(payload): Json<KeystoreImportRequest>,
+) -> Result<ResponseJson<WalletResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let keystore: Keystore = match payload.keystore {
+        serde_json::Value::String(json) => serde_json::from_str(&json),
+        value => serde_json::from_value(value),
+    }
+    .map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: format!("Invalid keystore JSON: {}", e) }),
+        )
+    })?;
+
+    let permit = kdf_permit().await?;
+    let result = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        EvmWallet::from_keystore(&keystore, payload.password.expose_secret())
+    })
+    .await;
+
+    match result {
+        Ok(Ok(wallet)) => wallet_response(wallet).map(ResponseJson),
+        Ok(Err(e)) => {
+            warn!("Failed to decrypt keystore: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+        Err(e) => {
+            warn!("Keystore decryption task failed: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: "Keystore decryption failed".to_string() }),
+            ))
+        }
+    }
+}
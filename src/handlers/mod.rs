@@ -2,7 +2,186 @@ pub mod creation;
 pub mod transaction;
 pub mod balance;
 pub mod system;
+pub mod keystore;
+pub mod vault;
+pub mod signing;
 pub use creation::*;
 pub use transaction::*;
 pub use balance::*;
-pub use system::*; 
\ No newline at end of file
+pub use system::*;
+pub use keystore::*;
+pub use vault::*;
+pub use signing::*;
+use std::time::Duration;
+use axum::{http::StatusCode, response::Json as ResponseJson};
+use alloy::primitives::{TxHash, U256};
+use crate::types::{AmountInput, ErrorResponse, TransactionResponse, TxOptions};
+use crate::wallet::{EvmWallet, WalletResponse, MAX_CONFIRMATIONS, MAX_CONFIRMATION_TIMEOUT};
+
+const DEFAULT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(120);
+
+pub(crate) fn check_address_inputs<'a>(
+    addresses: impl IntoIterator<Item = &'a str>,
+) -> Result<(), (StatusCode, ResponseJson<ErrorResponse>)> {
+    if !crate::utils::is_strict_address_checksum() {
+        return Ok(());
+    }
+    for address in addresses {
+        crate::utils::parse_address_strict(address).map_err(|e| {
+            tracing::warn!("Rejected address input: {}", e);
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            )
+        })?;
+    }
+    Ok(())
+}
+
+// `amount` is in whole units (e.g. "1.5" ETH), `amount_raw` in base units (wei / token units).
+pub(crate) fn parse_amount_input(
+    amount: Option<&AmountInput>,
+    amount_raw: Option<&str>,
+    decimals: u8,
+) -> Result<U256, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let parsed = match (amount, amount_raw) {
+        (Some(amount), None) => amount.as_decimal().and_then(|amount| crate::utils::parse_decimal_amount(amount, decimals)),
+        (None, Some(amount_raw)) => crate::utils::parse_raw_amount(amount_raw),
+        (Some(_), Some(_)) => Err(anyhow::anyhow!("Provide either amount or amount_raw, not both")),
+        (None, None) => Err(anyhow::anyhow!("amount or amount_raw is required")),
+    };
+    parsed.map_err(|e| {
+        tracing::warn!("Rejected amount input: {}", e);
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })
+}
+
+pub(crate) fn wallet_response(wallet: EvmWallet) -> Result<WalletResponse, (StatusCode, ResponseJson<ErrorResponse>)> {
+    WalletResponse::try_from(wallet).map_err(|e| {
+        tracing::warn!("Failed to build wallet response: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })
+}
+
+pub(crate) fn check_tx_options(options: &TxOptions) -> Result<(), (StatusCode, ResponseJson<ErrorResponse>)> {
+    crate::utils::validate_tx_options(options, &crate::utils::configured_fee_caps()).map_err(|e| {
+        tracing::warn!("Rejected transaction options: {}", e);
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })
+}
+
+pub(crate) async fn token_decimals_for_amount(
+    amount: Option<&AmountInput>,
+    token_address: &str,
+    rpc_url: &str,
+) -> Result<u8, (StatusCode, ResponseJson<ErrorResponse>)> {
+    // Raw amounts need no decimals; guessing 18 for a decimal amount could move the wrong quantity.
+    if amount.is_none() {
+        return Ok(0);
+    }
+    crate::utils::get_token_decimals(token_address, rpc_url).await.map_err(|e| {
+        tracing::warn!("Failed to get token decimals: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ResponseJson(ErrorResponse {
+                error: format!("Failed to read token decimals ({}); send amount_raw in base units instead", e),
+            }),
+        )
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ConfirmationWait {
+    confirmations: u64,
+    timeout: Duration,
+}
+
+// Validated before sending, so a bad value is rejected without a transaction going out.
+// `timeout_secs` alone waits for one confirmation; `confirmations: 0` does not wait.
+pub(crate) fn confirmation_wait(
+    confirmations: Option<u64>,
+    timeout_secs: Option<u64>,
+) -> Result<Option<ConfirmationWait>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let confirmations = match (confirmations, timeout_secs) {
+        (None, None) | (Some(0), _) => return Ok(None),
+        (Some(confirmations), _) => confirmations,
+        (None, Some(_)) => 1,
+    };
+    let timeout = timeout_secs.map(Duration::from_secs).unwrap_or(DEFAULT_CONFIRMATION_TIMEOUT);
+    let error = if confirmations > MAX_CONFIRMATIONS {
+        Some(format!("confirmations must be at most {}", MAX_CONFIRMATIONS))
+    } else if timeout.is_zero() || timeout > MAX_CONFIRMATION_TIMEOUT {
+        Some(format!("timeout_secs must be between 1 and {}", MAX_CONFIRMATION_TIMEOUT.as_secs()))
+    } else {
+        None
+    };
+    match error {
+        Some(error) => {
+            tracing::warn!("Rejected confirmation input: {}", error);
+            Err((StatusCode::BAD_REQUEST, ResponseJson(ErrorResponse { error })))
+        }
+        None => Ok(Some(ConfirmationWait { confirmations, timeout })),
+    }
+}
+
+pub(crate) async fn sent_transaction_response(
+    hash: TxHash,
+    wait: Option<ConfirmationWait>,
+    rpc_url: &str,
+) -> Result<ResponseJson<TransactionResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let mut response = TransactionResponse {
+        hash: format!("{:#x}", hash),
+        status: "pending".to_string(),
+        block_number: None,
+        confirmations: None,
+        gas_used: None,
+        effective_gas_price: None,
+        transaction_fee: None,
+    };
+    let Some(ConfirmationWait { confirmations, timeout }) = wait else {
+        return Ok(ResponseJson(response));
+    };
+
+    match EvmWallet::wait_for_confirmations(hash, confirmations, timeout, rpc_url).await {
+        Ok(Some(confirmed)) => {
+            tracing::info!("Transaction {:#x} confirmed in block {} (success={})", hash, confirmed.block_number, confirmed.success);
+            response.status = if confirmed.success { "success" } else { "reverted" }.to_string();
+            response.block_number = Some(confirmed.block_number);
+            response.confirmations = Some(confirmed.confirmations);
+            response.gas_used = Some(confirmed.gas_used);
+            response.effective_gas_price = Some(confirmed.effective_gas_price.to_string());
+            response.transaction_fee = Some(confirmed.transaction_fee.to_string());
+            Ok(ResponseJson(response))
+        }
+        Ok(None) => {
+            tracing::warn!("Transaction {:#x} did not reach {} confirmation(s) within {}s", hash, confirmations, timeout.as_secs());
+            Err((
+                StatusCode::GATEWAY_TIMEOUT,
+                ResponseJson(ErrorResponse {
+                    error: format!(
+                        "Transaction {:#x} was sent but did not reach {} confirmation(s) within {}s",
+                        hash, confirmations, timeout.as_secs()
+                    ),
+                }),
+            ))
+        }
+        Err(e) => {
+            tracing::warn!("Failed to wait for transaction {:#x}: {}", hash, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse {
+                    error: format!("Transaction {:#x} was sent but its confirmation could not be checked: {}", hash, e),
+                }),
+            ))
+        }
+    }
+}
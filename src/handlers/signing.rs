@@ -0,0 +1,133 @@
+use axum::{
+    extract::Json,
+    http::StatusCode,
+    response::Json as ResponseJson,
+};
+use alloy::primitives::eip191_hash_message;
+use tracing::{info, warn};
+use crate::wallet::*;
+use crate::types::*;
+use crate::secret::SecretString;
+use crate::utils::decode_message;
+use crate::handlers::vault::{resolve_wallet, VaultAuth};
+use crate::handlers::check_address_inputs;
+
+fn decode_message_payload(
+    message: &str,
+    encoding: Option<&str>,
+) -> Result<Vec<u8>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    decode_message(message, encoding).map_err(|e| {
+        warn!("Invalid message: {}", e);
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })
+}
+
+pub async fn sign_message(
+    auth: VaultAuth,
+    Json(payload): Json<SignMessageRequest>,
+) -> Result<ResponseJson<SignMessageResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let message = decode_message_payload(&payload.message, payload.encoding.as_deref())?;
+    let wallet = resolve_wallet(
+        &auth,
+        payload.private_key.as_ref().map(SecretString::expose_secret),
+        payload.wallet_id.as_deref(),
+    )?;
+
+    match wallet.sign_message(&message).await {
+        Ok(signature) => {
+            info!("Message signed: address={}", wallet.address);
+            Ok(ResponseJson(SignMessageResponse {
+                address: wallet.address.clone(),
+                message_hash: eip191_hash_message(&message).to_string(),
+                signature,
+            }))
+        }
+        Err(e) => {
+            warn!("Failed to sign message: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn verify_message(
+    Json(payload): Json<VerifyMessageRequest>,
+) -> Result<ResponseJson<MessageVerification>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    check_address_inputs([payload.address.as_str()])?;
+    let message = decode_message_payload(&payload.message, payload.encoding.as_deref())?;
+
+    match EvmWallet::verify_message(&message, &payload.signature, &payload.address) {
+        Ok(verification) => Ok(ResponseJson(verification)),
+        Err(e) => {
+            warn!("Failed to verify message: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn sign_typed_data(
+    auth: VaultAuth,
+    Json(payload): Json<SignTypedDataRequest>,
+) -> Result<ResponseJson<SignTypedDataResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let typed_data = &payload.typed_data;
+    let hashes = typed_data.hash_struct().and_then(|struct_hash| {
+        typed_data.eip712_signing_hash().map(|signing_hash| (struct_hash, signing_hash))
+    });
+    let (struct_hash, signing_hash) = hashes.map_err(|e| {
+        warn!("Invalid typed data: {}", e);
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: format!("Invalid typed data: {}", e) }),
+        )
+    })?;
+    let wallet = resolve_wallet(
+        &auth,
+        payload.private_key.as_ref().map(SecretString::expose_secret),
+        payload.wallet_id.as_deref(),
+    )?;
+
+    match wallet.sign_typed_data(typed_data).await {
+        Ok(signature) => {
+            info!("Typed data signed: address={}, primary_type={}", wallet.address, typed_data.primary_type);
+            Ok(ResponseJson(SignTypedDataResponse {
+                address: wallet.address.clone(),
+                domain_separator: typed_data.domain.separator().to_string(),
+                struct_hash: struct_hash.to_string(),
+                signing_hash: signing_hash.to_string(),
+                signature,
+            }))
+        }
+        Err(e) => {
+            warn!("Failed to sign typed data: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn verify_typed_data(
+    Json(payload): Json<VerifyTypedDataRequest>,
+) -> Result<ResponseJson<MessageVerification>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    check_address_inputs([payload.address.as_str()])?;
+
+    match EvmWallet::verify_typed_data(&payload.typed_data, &payload.signature, &payload.address) {
+        Ok(verification) => Ok(ResponseJson(verification)),
+        Err(e) => {
+            warn!("Failed to verify typed data: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
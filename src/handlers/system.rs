@@ -7,6 +7,7 @@ use tracing::warn;
 use crate::wallet::*;
 use crate::types::*;
 use crate::utils::*;
+use crate::handlers::check_address_inputs;
 
 pub async fn health_check() -> &'static str {
     "EVM Wallet API is running!"
@@ -40,6 +41,7 @@ pub async fn get_transaction_details(
 pub async fn get_native_transaction_history(
     Json(payload): Json<NativeTransactionHistoryRequest>,
 ) -> Result<ResponseJson<TransactionHistoryResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    check_address_inputs([payload.address.as_str()])?;
     let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
     match EvmWallet::get_native_transactions_by_block_range(
         &payload.address,
@@ -63,6 +65,7 @@ pub async fn get_native_transaction_history(
 pub async fn get_erc20_events(
     Json(payload): Json<Erc20EventsRequest>,
 ) -> Result<ResponseJson<serde_json::Value>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    check_address_inputs(std::iter::once(payload.token_address.as_str()).chain(payload.address_filter.as_deref()))?;
     let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
     match EvmWallet::get_erc20_transfer_events(
         &payload.token_address,
@@ -4,205 +4,213 @@ use axum::{
     response::Json as ResponseJson,
 };
 use tracing::{warn, info, debug};
-use alloy::primitives::U256;
 use crate::wallet::*;
 use crate::types::*;
+use crate::secret::SecretString;
 use crate::utils::*;
+use crate::handlers::vault::{resolve_wallet, VaultAuth};
+use crate::handlers::{check_address_inputs, check_tx_options, confirmation_wait, parse_amount_input, sent_transaction_response, token_decimals_for_amount};
 
 pub async fn send_native_coin(
+    auth: VaultAuth,
     Json(payload): Json<SendTransactionRequest>,
 ) -> Result<ResponseJson<TransactionResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    info!("Native coin transfer request: to={}, amount={}, network={:?}", 
-          payload.to, payload.amount, payload.network);
+    info!("Native coin transfer request: to={}, amount={:?}, amount_raw={:?}, network={:?}", 
+          payload.to, payload.amount, payload.amount_raw, payload.network);
     
-    match EvmWallet::create_wallet_from_private_key(&payload.private_key) {
-        Ok(wallet) => {
-            let wei_amount = (payload.amount * 1_000_000_000_000_000_000.0) as u128;
-            let amount = U256::from(wei_amount);
-            debug!("Converted amount: {} ETH -> {} wei", payload.amount, wei_amount);
-
-            let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
-            debug!("Using RPC URL: {}", rpc_url);
-            
-            match wallet.send_native_coin(&payload.to, amount, &rpc_url).await {
-                Ok(hash) => {
-                    info!("Native coin transfer successful: tx_hash={:#x}", hash);
-                    Ok(ResponseJson(TransactionResponse {
-                        hash: format!("{:#x}", hash),
-                    }))
-                },
-                Err(e) => {
-                    warn!("Failed to send native coin: {}", e);
-                    let error_msg = if e.to_string().contains("insufficient") {
-                        "Insufficient funds for transaction. Please check your balance and gas requirements."
-                    } else if e.to_string().contains("network") || e.to_string().contains("connection") {
-                        "Network connection failed. Please check your network configuration and try again."
-                    } else if e.to_string().contains("gas") {
-                        "Gas estimation failed. The transaction may be too complex or the network is congested."
-                    } else {
-                        &e.to_string()
-                    };
-                    Err((
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        ResponseJson(ErrorResponse { error: error_msg.to_string() }),
-                    ))
-                }
-            }
-        }
+    check_address_inputs([payload.to.as_str()])?;
+    let amount = parse_amount_input(payload.amount.as_ref(), payload.amount_raw.as_deref(), 18)?;
+    debug!("Converted amount: {:?} ETH -> {} wei", payload.amount, amount);
+    let wait = confirmation_wait(payload.confirmations, payload.timeout_secs)?;
+    let options = TxOptions {
+        tx_type: payload.tx_type,
+        gas_limit: payload.gas_limit,
+        max_fee_per_gas: payload.max_fee_per_gas,
+        max_priority_fee_per_gas: payload.max_priority_fee_per_gas,
+        gas_price: payload.gas_price,
+        nonce: payload.nonce,
+    };
+    check_tx_options(&options)?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+    debug!("Using RPC URL: {}", rpc_url);
+    
+    match wallet.send_native_coin_with_options(&payload.to, amount, &rpc_url, &options).await {
+        Ok(hash) => {
+            info!("Native coin transfer successful: tx_hash={:#x}", hash);
+            sent_transaction_response(hash, wait, &rpc_url).await
+        },
         Err(e) => {
-            warn!("Invalid private key: {}", e);
+            warn!("Failed to send native coin: {}", e);
+            let error_msg = if e.to_string().contains("insufficient") {
+                "Insufficient funds for transaction. Please check your balance and gas requirements."
+            } else if e.to_string().contains("network") || e.to_string().contains("connection") {
+                "Network connection failed. Please check your network configuration and try again."
+            } else if e.to_string().contains("gas") {
+                "Gas estimation failed. The transaction may be too complex or the network is congested."
+            } else {
+                &e.to_string()
+            };
             Err((
-                StatusCode::BAD_REQUEST,
-                ResponseJson(ErrorResponse { error: format!("Invalid private key: {}", e) }),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: error_msg.to_string() }),
             ))
         }
     }
 }
 
 pub async fn send_erc20_token(
+    auth: VaultAuth,
     Json(payload): Json<SendErc20Request>,
 ) -> Result<ResponseJson<TransactionResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    info!("ERC20 token transfer request: to={}, amount={}, token={}, network={:?}", 
-          payload.to, payload.amount, payload.token_address, payload.network);
+    info!("ERC20 token transfer request: to={}, amount={:?}, amount_raw={:?}, token={}, network={:?}", 
+          payload.to, payload.amount, payload.amount_raw, payload.token_address, payload.network);
     
-    match EvmWallet::create_wallet_from_private_key(&payload.private_key) {
-        Ok(wallet) => {
-            let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
-            
-            let decimals = match crate::utils::get_token_decimals(&payload.token_address, &rpc_url).await {
-                Ok(decimals) => {
-                    info!("Token decimals: {}", decimals);
-                    decimals
-                },
-                Err(e) => {
-                    warn!("Failed to get token decimals, using default 18: {}", e);
-                    18
-                }
-            };
-            
-            let multiplier = 10_u128.pow(decimals as u32);
-            let token_amount = (payload.amount * multiplier as f64) as u128;
-            let amount = U256::from(token_amount);
-            
-            info!("Token decimals: {}, Multiplier: {}, Amount: {} -> {} (raw)", 
-                  decimals, multiplier, payload.amount, amount);
-
-            match wallet.send_erc20_token(&payload.to, amount, &payload.token_address, &rpc_url).await {
-                Ok(hash) => {
-                    info!("ERC20 token transfer successful: tx_hash={:#x}", hash);
-                    Ok(ResponseJson(TransactionResponse {
-                        hash: format!("{:#x}", hash),
-                    }))
-                },
-                Err(e) => {
-                    warn!("Failed to send ERC20 token: {}", e);
-                    Err((
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        ResponseJson(ErrorResponse { error: e.to_string() }),
-                    ))
-                }
-            }
+    check_address_inputs([payload.to.as_str(), payload.token_address.as_str()])?;
+    let wait = confirmation_wait(payload.confirmations, payload.timeout_secs)?;
+    let options = TxOptions {
+        tx_type: payload.tx_type,
+        gas_limit: payload.gas_limit,
+        max_fee_per_gas: payload.max_fee_per_gas,
+        max_priority_fee_per_gas: payload.max_priority_fee_per_gas,
+        gas_price: payload.gas_price,
+        nonce: payload.nonce,
+    };
+    check_tx_options(&options)?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+    
+    // Decimal amounts need the token's decimals, which is the first RPC call; it runs only for an authorized wallet.
+    let decimals = token_decimals_for_amount(payload.amount.as_ref(), &payload.token_address, &rpc_url).await?;
+    let amount = parse_amount_input(payload.amount.as_ref(), payload.amount_raw.as_deref(), decimals)?;
+    
+    info!("Token decimals: {}, Amount: {:?} -> {} (raw)", 
+          decimals, payload.amount, amount);
+
+    match wallet.send_erc20_token_with_options(&payload.to, amount, &payload.token_address, &rpc_url, &options).await {
+        Ok(hash) => {
+            info!("ERC20 token transfer successful: tx_hash={:#x}", hash);
+            sent_transaction_response(hash, wait, &rpc_url).await
+        },
+        Err(e) => {
+            warn!("Failed to send ERC20 token: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
         }
+    }
+}
+
+pub async fn sweep(
+    auth: VaultAuth,
+    Json(payload): Json<SweepRequest>,
+) -> Result<ResponseJson<SweepResult>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let token_addresses = payload.token_addresses.unwrap_or_default();
+    info!("Sweep request: to={}, tokens={}, network={:?}", 
+          payload.to, token_addresses.len(), payload.network);
+    
+    check_address_inputs(std::iter::once(payload.to.as_str()).chain(token_addresses.iter().map(String::as_str)))?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+
+    match wallet.sweep_with_type(&payload.to, &token_addresses, &rpc_url, payload.tx_type).await {
+        Ok(result) => {
+            info!("Sweep from {} to {} sent {} transaction(s)", result.from, result.to, result.tx_hashes.len());
+            Ok(ResponseJson(result))
+        },
         Err(e) => {
-            warn!("Invalid private key: {}", e);
+            warn!("Failed to sweep wallet: {}", e);
             Err((
-                StatusCode::BAD_REQUEST,
-                ResponseJson(ErrorResponse { error: format!("Invalid private key: {}", e) }),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
             ))
         }
     }
 }
 
 pub async fn estimate_gas(
+    auth: VaultAuth,
     Json(payload): Json<EstimateGasRequest>,
 ) -> Result<ResponseJson<GasEstimateResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    match EvmWallet::create_wallet_from_private_key(&payload.private_key) {
-        Ok(wallet) => {
-            let wei_amount = (payload.amount * 1_000_000_000_000_000_000.0) as u128;
-            let amount = U256::from(wei_amount);
-
-            let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
-            match wallet.estimate_gas(&payload.to, amount, &rpc_url).await {
-                Ok((gas_limit, gas_price, total_fee)) => Ok(ResponseJson(GasEstimateResponse { 
-                    gas_limit, 
-                    gas_price,
-                    total_fee
-                })),
-                Err(e) => {
-                    warn!("Failed to estimate gas: {}", e);
-                    let error_msg = if e.to_string().contains("network") || e.to_string().contains("connection") {
-                        "Network connection failed. Please check your network configuration and try again."
-                    } else if e.to_string().contains("revert") {
-                        "Transaction would fail. Please check the recipient address and contract state."
-                    } else {
-                        &e.to_string()
-                    };
-                    Err((
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        ResponseJson(ErrorResponse { error: error_msg.to_string() }),
-                    ))
-                }
-            }
-        }
+    check_address_inputs([payload.to.as_str()])?;
+    let amount = parse_amount_input(payload.amount.as_ref(), payload.amount_raw.as_deref(), 18)?;
+    let options = TxOptions {
+        tx_type: payload.tx_type,
+        gas_limit: payload.gas_limit,
+        max_fee_per_gas: payload.max_fee_per_gas,
+        max_priority_fee_per_gas: payload.max_priority_fee_per_gas,
+        gas_price: payload.gas_price,
+        nonce: None,
+    };
+    check_tx_options(&options)?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+    match wallet.estimate_gas_with_options(&payload.to, amount, &rpc_url, &options).await {
+        Ok((gas_limit, gas_price, total_fee)) => Ok(ResponseJson(GasEstimateResponse { 
+            gas_limit, 
+            gas_price,
+            total_fee
+        })),
         Err(e) => {
-            warn!("Invalid private key: {}", e);
+            warn!("Failed to estimate gas: {}", e);
+            let error_msg = if e.to_string().contains("network") || e.to_string().contains("connection") {
+                "Network connection failed. Please check your network configuration and try again."
+            } else if e.to_string().contains("revert") {
+                "Transaction would fail. Please check the recipient address and contract state."
+            } else {
+                &e.to_string()
+            };
             Err((
-                StatusCode::BAD_REQUEST,
-                ResponseJson(ErrorResponse { error: format!("Invalid private key: {}", e) }),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: error_msg.to_string() }),
             ))
         }
     }
 }
 
 pub async fn estimate_erc20_gas(
+    auth: VaultAuth,
     Json(payload): Json<EstimateErc20GasRequest>,
 ) -> Result<ResponseJson<GasEstimateResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
-    match EvmWallet::create_wallet_from_private_key(&payload.private_key) {
-        Ok(wallet) => {
-            let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
-            
-            let decimals = match crate::utils::get_token_decimals(&payload.token_address, &rpc_url).await {
-                Ok(decimals) => decimals,
-                Err(e) => {
-                    warn!("Failed to get token decimals, using default 18: {}", e);
-                    18
-                }
-            };
-            
-            let multiplier = 10_u128.pow(decimals as u32);
-            let token_amount = (payload.amount * multiplier as f64) as u128;
-            let amount = U256::from(token_amount);
-            
-            warn!("Token decimals: {}, Multiplier: {}, Calculated amount: {}", decimals, multiplier, amount);
-            match wallet.estimate_erc20_gas(&payload.to, amount, &payload.token_address, &rpc_url).await {
-                Ok((gas_limit, gas_price, total_fee)) => Ok(ResponseJson(GasEstimateResponse { 
-                    gas_limit, 
-                    gas_price,
-                    total_fee
-                })),
-                Err(e) => {
-                    warn!("Failed to estimate ERC20 gas: {}", e);
-                    let error_msg = if e.to_string().contains("network") || e.to_string().contains("connection") {
-                        "Network connection failed. Please check your network configuration and try again."
-                    } else if e.to_string().contains("revert") {
-                        "Transaction would fail. Please check the recipient address, token contract, and your token balance."
-                    } else if e.to_string().contains("insufficient") {
-                        "Insufficient token balance for this transaction."
-                    } else {
-                        &e.to_string()
-                    };
-                    Err((
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        ResponseJson(ErrorResponse { error: error_msg.to_string() }),
-                    ))
-                }
-            }
-        }
+    check_address_inputs([payload.to.as_str(), payload.token_address.as_str()])?;
+    let options = TxOptions {
+        tx_type: payload.tx_type,
+        gas_limit: payload.gas_limit,
+        max_fee_per_gas: payload.max_fee_per_gas,
+        max_priority_fee_per_gas: payload.max_priority_fee_per_gas,
+        gas_price: payload.gas_price,
+        nonce: None,
+    };
+    check_tx_options(&options)?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+    
+    let decimals = token_decimals_for_amount(payload.amount.as_ref(), &payload.token_address, &rpc_url).await?;
+    let amount = parse_amount_input(payload.amount.as_ref(), payload.amount_raw.as_deref(), decimals)?;
+    
+    warn!("Token decimals: {}, Calculated amount: {}", decimals, amount);
+    match wallet.estimate_erc20_gas_with_options(&payload.to, amount, &payload.token_address, &rpc_url, &options).await {
+        Ok((gas_limit, gas_price, total_fee)) => Ok(ResponseJson(GasEstimateResponse { 
+            gas_limit, 
+            gas_price,
+            total_fee
+        })),
         Err(e) => {
-            warn!("Invalid private key: {}", e);
+            warn!("Failed to estimate ERC20 gas: {}", e);
+            let error_msg = if e.to_string().contains("network") || e.to_string().contains("connection") {
+                "Network connection failed. Please check your network configuration and try again."
+            } else if e.to_string().contains("revert") {
+                "Transaction would fail. Please check the recipient address, token contract, and your token balance."
+            } else if e.to_string().contains("insufficient") {
+                "Insufficient token balance for this transaction."
+            } else {
+                &e.to_string()
+            };
             Err((
-                StatusCode::BAD_REQUEST,
-                ResponseJson(ErrorResponse { error: format!("Invalid private key: {}", e) }),
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: error_msg.to_string() }),
             ))
         }
     }
@@ -252,4 +260,174 @@ pub async fn get_transaction_receipt(
             ))
         }
     }
-}
\ No newline at end of file
+}
+
+pub async fn sign_native_transaction(
+    auth: VaultAuth,
+    Json(payload): Json<SignNativeRequest>,
+) -> Result<ResponseJson<SignedTransaction>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    info!("Offline native transfer signing request: to={}, amount_raw={}, chain_id={}, nonce={}",
+          payload.to, payload.amount_raw, payload.chain_id, payload.nonce);
+
+    check_address_inputs([payload.to.as_str()])?;
+    let amount = parse_raw_amount(&payload.amount_raw).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let params = OfflineTxParams {
+        chain_id: payload.chain_id,
+        nonce: payload.nonce,
+        gas_limit: payload.gas_limit,
+        max_fee_per_gas: payload.max_fee_per_gas,
+        max_priority_fee_per_gas: payload.max_priority_fee_per_gas,
+        gas_price: payload.gas_price,
+        tx_type: payload.tx_type,
+    };
+
+    match wallet.sign_native_transaction(&payload.to, amount, &params).await {
+        Ok(signed) => {
+            info!("Native transfer signed offline: tx_hash={}", signed.tx_hash);
+            Ok(ResponseJson(signed))
+        }
+        Err(e) => {
+            warn!("Failed to sign native transfer: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn sign_erc20_transaction(
+    auth: VaultAuth,
+    Json(payload): Json<SignErc20Request>,
+) -> Result<ResponseJson<SignedTransaction>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    info!("Offline ERC20 transfer signing request: to={}, amount_raw={}, token={}, chain_id={}, nonce={}",
+          payload.to, payload.amount_raw, payload.token_address, payload.chain_id, payload.nonce);
+
+    check_address_inputs([payload.to.as_str(), payload.token_address.as_str()])?;
+    let amount = parse_raw_amount(&payload.amount_raw).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })?;
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let params = OfflineTxParams {
+        chain_id: payload.chain_id,
+        nonce: payload.nonce,
+        gas_limit: payload.gas_limit,
+        max_fee_per_gas: payload.max_fee_per_gas,
+        max_priority_fee_per_gas: payload.max_priority_fee_per_gas,
+        gas_price: payload.gas_price,
+        tx_type: payload.tx_type,
+    };
+
+    match wallet.sign_erc20_transaction(&payload.to, amount, &payload.token_address, &params).await {
+        Ok(signed) => {
+            info!("ERC20 transfer signed offline: tx_hash={}", signed.tx_hash);
+            Ok(ResponseJson(signed))
+        }
+        Err(e) => {
+            warn!("Failed to sign ERC20 transfer: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn decode_raw_transaction(
+    Json(payload): Json<RawTransactionRequest>,
+) -> Result<ResponseJson<DecodedTransaction>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    match EvmWallet::decode_raw_transaction(&payload.raw_transaction) {
+        Ok(decoded) => Ok(ResponseJson(decoded)),
+        Err(e) => {
+            warn!("Failed to decode raw transaction: {}", e);
+            Err((
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn broadcast_raw_transaction(
+    Json(payload): Json<RawTransactionRequest>,
+) -> Result<ResponseJson<BroadcastRawResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    // 브로드캐스트 전에 디코딩해서 잘못된 입력은 400으로 거절
+    if let Err(e) = EvmWallet::decode_raw_transaction(&payload.raw_transaction) {
+        warn!("Rejected raw transaction: {}", e);
+        return Err((
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        ));
+    }
+
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+    match EvmWallet::broadcast_raw_transaction(&payload.raw_transaction, &rpc_url).await {
+        Ok((hash, transaction)) => {
+            info!("Raw transaction broadcast: tx_hash={:#x}, from={}, nonce={}", hash, transaction.from, transaction.nonce);
+            Ok(ResponseJson(BroadcastRawResponse {
+                hash: format!("{:#x}", hash),
+                transaction,
+            }))
+        }
+        Err(e) => {
+            warn!("Failed to broadcast raw transaction: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
+
+pub async fn speed_up_transaction(
+    auth: VaultAuth,
+    Json(payload): Json<ReplaceTransactionRequest>,
+) -> Result<ResponseJson<ReplacementTransaction>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    replace_transaction(auth, payload, ReplacementKind::SpeedUp).await
+}
+
+pub async fn cancel_transaction(
+    auth: VaultAuth,
+    Json(payload): Json<ReplaceTransactionRequest>,
+) -> Result<ResponseJson<ReplacementTransaction>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    replace_transaction(auth, payload, ReplacementKind::Cancel).await
+}
+
+async fn replace_transaction(
+    auth: VaultAuth,
+    payload: ReplaceTransactionRequest,
+    kind: ReplacementKind,
+) -> Result<ResponseJson<ReplacementTransaction>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    info!("Transaction replacement request: action={}, tx_hash={}, network={:?}",
+          kind.as_str(), payload.tx_hash, payload.network);
+
+    let wallet = resolve_wallet(&auth, payload.private_key.as_ref().map(SecretString::expose_secret), payload.wallet_id.as_deref())?;
+    let rpc_url = get_rpc_url_for_network(payload.network.as_deref());
+
+    match wallet.replace_transaction(&payload.tx_hash, kind, &rpc_url).await {
+        Ok(replacement) => {
+            info!("Transaction {} replaced by {} (action={}, nonce={})",
+                  replacement.original_hash, replacement.hash, replacement.action, replacement.nonce);
+            Ok(ResponseJson(replacement))
+        }
+        Err(e) => {
+            warn!("Failed to replace transaction {}: {}", payload.tx_hash, e);
+            let status = match e {
+                ReplacementError::InvalidHash(_) => StatusCode::BAD_REQUEST,
+                ReplacementError::NotFound(_) => StatusCode::NOT_FOUND,
+                ReplacementError::NotReplaceable(_) => StatusCode::CONFLICT,
+                ReplacementError::Failed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            Err((status, ResponseJson(ErrorResponse { error: e.to_string() })))
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::convert::Infallible;
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Json},
+    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+    response::Json as ResponseJson,
+};
+use tracing::{warn, info};
+use crate::vault::*;
+use crate::wallet::*;
+use crate::types::*;
+use crate::secret::SecretString;
+
+// Whether the request carries `Authorization: Bearer <VAULT_API_TOKEN>`. It never rejects by itself,
+// so `private_key` requests keep working without a token; vault access checks it with `require`.
+#[derive(Debug, Clone, Copy)]
+pub struct VaultAuth {
+    authorized: bool,
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for VaultAuth {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let authorization = parts.headers.get(AUTHORIZATION).and_then(|value| value.to_str().ok());
+        Ok(VaultAuth { authorized: vault_token_authorized(authorization) })
+    }
+}
+
+impl VaultAuth {
+    pub(crate) fn require(&self) -> Result<(), (StatusCode, ResponseJson<ErrorResponse>)> {
+        if self.authorized {
+            return Ok(());
+        }
+        warn!("Rejected unauthenticated vault access");
+        Err((
+            StatusCode::UNAUTHORIZED,
+            ResponseJson(ErrorResponse { error: "Vault access requires Authorization: Bearer <VAULT_API_TOKEN>".to_string() }),
+        ))
+    }
+}
+
+pub(crate) fn resolve_wallet(
+    auth: &VaultAuth,
+    private_key: Option<&str>,
+    wallet_id: Option<&str>,
+) -> Result<EvmWallet, (StatusCode, ResponseJson<ErrorResponse>)> {
+    match (private_key, wallet_id) {
+        (Some(_), Some(_)) => Err((
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: "Provide either private_key or wallet_id, not both".to_string() }),
+        )),
+        (Some(private_key), None) => EvmWallet::create_wallet_from_private_key(private_key).map_err(|e| {
+            warn!("Invalid private key: {}", e);
+            (
+                StatusCode::BAD_REQUEST,
+                ResponseJson(ErrorResponse { error: format!("Invalid private key: {}", e) }),
+            )
+        }),
+        (None, Some(wallet_id)) => {
+            let vault = vault_or_error()?;
+            auth.require()?;
+            vault.get_wallet(wallet_id).map_err(|e| {
+                warn!("Vault lookup failed: {}", e);
+                (
+                    StatusCode::NOT_FOUND,
+                    ResponseJson(ErrorResponse { error: e.to_string() }),
+                )
+            })
+        }
+        (None, None) => Err((
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ErrorResponse { error: "Either private_key or wallet_id is required".to_string() }),
+        )),
+    }
+}
+
+pub(crate) fn vault_or_error() -> Result<&'static WalletVault, (StatusCode, ResponseJson<ErrorResponse>)> {
+    global_vault().map_err(|e| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            ResponseJson(ErrorResponse { error: e.to_string() }),
+        )
+    })
+}
+
+pub async fn create_vault_wallet(
+    auth: VaultAuth,
+    Json(payload): Json<VaultCreateRequest>,
+) -> Result<ResponseJson<VaultWalletInfo>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let vault = vault_or_error()?;
+    auth.require()?;
+    let result = match payload.private_key.as_ref().map(SecretString::expose_secret) {
+        Some(private_key) => vault.import_private_key(private_key),
+        None => vault.create_wallet(),
+    };
+
+    match result {
+        Ok(info) => {
+            info!("Stored wallet in vault: wallet_id={}, address={}", info.wallet_id, info.address);
+            Ok(ResponseJson(info))
+        }
+        Err(e) => {
+            warn!("Failed to store wallet in vault: {}", e);
+            let status = if payload.private_key.is_some() {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            Err((status, ResponseJson(ErrorResponse { error: e.to_string() })))
+        }
+    }
+}
+
+pub async fn list_vault_wallets(
+    auth: VaultAuth,
+) -> Result<ResponseJson<VaultListResponse>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let vault = vault_or_error()?;
+    auth.require()?;
+    Ok(ResponseJson(VaultListResponse { wallets: vault.list() }))
+}
+
+pub async fn delete_vault_wallet(
+    auth: VaultAuth,
+    Json(payload): Json<VaultDeleteRequest>,
+) -> Result<ResponseJson<serde_json::Value>, (StatusCode, ResponseJson<ErrorResponse>)> {
+    let vault = vault_or_error()?;
+    auth.require()?;
+    match vault.delete(&payload.wallet_id) {
+        Ok(()) => {
+            info!("Deleted wallet from vault: wallet_id={}", payload.wallet_id);
+            Ok(ResponseJson(serde_json::json!({
+                "wallet_id": payload.wallet_id,
+                "deleted": true
+            })))
+        }
+        Err(e) => {
+            warn!("Failed to delete vault wallet: {}", e);
+            Err((
+                StatusCode::NOT_FOUND,
+                ResponseJson(ErrorResponse { error: e.to_string() }),
+            ))
+        }
+    }
+}
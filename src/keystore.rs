@@ -0,0 +1,224 @@
+use aes::cipher::{KeyIvInit, StreamCipher};
+use alloy::primitives::{keccak256, Address};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+const DERIVED_KEY_LENGTH: usize = 32;
+// Upper bounds for KDF parameters accepted on import, so an untrusted keystore cannot exhaust
+// memory or CPU. Every parameter that scales the work is bounded, not just n and c.
+const MAX_DERIVED_KEY_LENGTH: usize = 64;
+const MAX_SCRYPT_N: u32 = 1 << 20;
+const MAX_SCRYPT_R: u32 = 32;
+const MAX_SCRYPT_P: u32 = 16;
+// 128 * n * r * p, i.e. 256 MiB: exactly the default n = 2^18, r = 8, p = 1.
+const MAX_SCRYPT_COST: u64 = 1 << 28;
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeystoreKdf {
+    Scrypt { n: u32, r: u32, p: u32 },
+    Pbkdf2 { c: u32 },
+}
+
+impl KeystoreKdf {
+    pub fn scrypt() -> Self {
+        KeystoreKdf::Scrypt { n: 262_144, r: 8, p: 1 }
+    }
+
+    pub fn pbkdf2() -> Self {
+        KeystoreKdf::Pbkdf2 { c: 262_144 }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "scrypt" => Ok(Self::scrypt()),
+            "pbkdf2" => Ok(Self::pbkdf2()),
+            _ => Err(anyhow::anyhow!("Unsupported KDF: {}. Use scrypt or pbkdf2", name)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(alias = "Crypto")]
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub cipher: String,
+    pub cipherparams: CipherParams,
+    pub ciphertext: String,
+    pub kdf: String,
+    pub kdfparams: KdfParams,
+    pub mac: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CipherParams {
+    pub iv: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KdfParams {
+    Scrypt {
+        dklen: usize,
+        n: u32,
+        r: u32,
+        p: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        c: u32,
+        dklen: usize,
+        prf: String,
+        salt: String,
+    },
+}
+
+pub fn encrypt_key(private_key: &[u8], password: &str, kdf: KeystoreKdf) -> Result<Keystore> {
+    let mut salt = [0u8; 32];
+    let mut iv = [0u8; 16];
+    getrandom::getrandom(&mut salt)?;
+    getrandom::getrandom(&mut iv)?;
+
+    let (kdf_name, kdfparams) = match kdf {
+        KeystoreKdf::Scrypt { n, r, p } => ("scrypt", KdfParams::Scrypt {
+            dklen: DERIVED_KEY_LENGTH,
+            n,
+            r,
+            p,
+            salt: hex::encode(salt),
+        }),
+        KeystoreKdf::Pbkdf2 { c } => ("pbkdf2", KdfParams::Pbkdf2 {
+            c,
+            dklen: DERIVED_KEY_LENGTH,
+            prf: "hmac-sha256".to_string(),
+            salt: hex::encode(salt),
+        }),
+    };
+    let derived_key = derive_key(password, &kdfparams)?;
+
+    let mut ciphertext = private_key.to_vec();
+    Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into()).apply_keystream(&mut ciphertext);
+    let mac = keccak256(Zeroizing::new([&derived_key[16..32], ciphertext.as_slice()].concat()));
+
+    let signing_key = k256::ecdsa::SigningKey::from_slice(private_key)?;
+    let address = alloy::signers::utils::public_key_to_address(signing_key.verifying_key());
+
+    Ok(Keystore {
+        version: 3,
+        id: uuid::Uuid::new_v4().to_string(),
+        address: Some(hex::encode(address)),
+        crypto: KeystoreCrypto {
+            cipher: "aes-128-ctr".to_string(),
+            cipherparams: CipherParams { iv: hex::encode(iv) },
+            ciphertext: hex::encode(ciphertext),
+            kdf: kdf_name.to_string(),
+            kdfparams,
+            mac: hex::encode(mac),
+        },
+    })
+}
+
+pub fn decrypt_key(keystore: &Keystore, password: &str) -> Result<Zeroizing<Vec<u8>>> {
+    if keystore.version != 3 {
+        return Err(anyhow::anyhow!("Unsupported keystore version: {}", keystore.version));
+    }
+    let crypto = &keystore.crypto;
+    if crypto.cipher != "aes-128-ctr" {
+        return Err(anyhow::anyhow!("Unsupported keystore cipher: {}", crypto.cipher));
+    }
+    match (crypto.kdf.as_str(), &crypto.kdfparams) {
+        ("scrypt", KdfParams::Scrypt { .. }) | ("pbkdf2", KdfParams::Pbkdf2 { .. }) => {}
+        _ => return Err(anyhow::anyhow!("kdfparams do not match kdf {}", crypto.kdf)),
+    }
+
+    let derived_key = derive_key(password, &crypto.kdfparams)?;
+    let ciphertext = hex::decode(crypto.ciphertext.trim_start_matches("0x"))
+        .map_err(|e| anyhow::anyhow!("Invalid keystore ciphertext: {}", e))?;
+    let expected_mac = hex::decode(crypto.mac.trim_start_matches("0x"))
+        .map_err(|e| anyhow::anyhow!("Invalid keystore mac: {}", e))?;
+    let mac = keccak256(Zeroizing::new([&derived_key[16..32], ciphertext.as_slice()].concat()));
+    if mac.as_slice() != expected_mac.as_slice() {
+        return Err(anyhow::anyhow!("Keystore MAC mismatch: wrong password or corrupted keystore"));
+    }
+
+    let iv = hex::decode(crypto.cipherparams.iv.trim_start_matches("0x"))
+        .map_err(|e| anyhow::anyhow!("Invalid keystore iv: {}", e))?;
+    if iv.len() != 16 {
+        return Err(anyhow::anyhow!("Invalid keystore iv length: {}", iv.len()));
+    }
+    let mut private_key = Zeroizing::new(ciphertext);
+    Aes128Ctr::new(derived_key[..16].into(), iv.as_slice().into()).apply_keystream(&mut private_key);
+
+    if let Some(address) = &keystore.address {
+        let signing_key = k256::ecdsa::SigningKey::from_slice(&private_key)?;
+        let derived_address = alloy::signers::utils::public_key_to_address(signing_key.verifying_key());
+        let expected_address: Address = format!("0x{}", address.trim_start_matches("0x")).parse()
+            .map_err(|e| anyhow::anyhow!("Invalid keystore address: {}", e))?;
+        if derived_address != expected_address {
+            return Err(anyhow::anyhow!("Keystore address does not match the decrypted key"));
+        }
+    }
+
+    Ok(private_key)
+}
+
+fn check_dklen(dklen: usize) -> Result<()> {
+    if !(DERIVED_KEY_LENGTH..=MAX_DERIVED_KEY_LENGTH).contains(&dklen) {
+        return Err(anyhow::anyhow!("Unsupported keystore dklen: {} (must be between {} and {})", dklen, DERIVED_KEY_LENGTH, MAX_DERIVED_KEY_LENGTH));
+    }
+    Ok(())
+}
+
+fn derive_key(password: &str, kdfparams: &KdfParams) -> Result<Zeroizing<Vec<u8>>> {
+    match kdfparams {
+        KdfParams::Scrypt { dklen, n, r, p, salt } => {
+            check_dklen(*dklen)?;
+            if !n.is_power_of_two() || *n < 2 || *n > MAX_SCRYPT_N {
+                return Err(anyhow::anyhow!("Unsupported scrypt n: {} (must be a power of two up to {})", n, MAX_SCRYPT_N));
+            }
+            if *r == 0 || *r > MAX_SCRYPT_R {
+                return Err(anyhow::anyhow!("Unsupported scrypt r: {} (must be between 1 and {})", r, MAX_SCRYPT_R));
+            }
+            if *p == 0 || *p > MAX_SCRYPT_P {
+                return Err(anyhow::anyhow!("Unsupported scrypt p: {} (must be between 1 and {})", p, MAX_SCRYPT_P));
+            }
+            if 128 * u64::from(*n) * u64::from(*r) * u64::from(*p) > MAX_SCRYPT_COST {
+                return Err(anyhow::anyhow!("Unsupported scrypt parameters: 128 * n * r * p must not exceed {} bytes", MAX_SCRYPT_COST));
+            }
+            let salt = hex::decode(salt.trim_start_matches("0x"))
+                .map_err(|e| anyhow::anyhow!("Invalid keystore salt: {}", e))?;
+            let params = scrypt::Params::new(n.trailing_zeros() as u8, *r, *p, *dklen)
+                .map_err(|e| anyhow::anyhow!("Invalid scrypt parameters: {}", e))?;
+            let mut derived_key = Zeroizing::new(vec![0u8; *dklen]);
+            scrypt::scrypt(password.as_bytes(), &salt, &params, &mut derived_key)
+                .map_err(|e| anyhow::anyhow!("scrypt failed: {}", e))?;
+            Ok(derived_key)
+        }
+        KdfParams::Pbkdf2 { c, dklen, prf, salt } => {
+            check_dklen(*dklen)?;
+            if prf != "hmac-sha256" {
+                return Err(anyhow::anyhow!("Unsupported pbkdf2 prf: {}", prf));
+            }
+            if *c == 0 || *c > MAX_PBKDF2_ITERATIONS {
+                return Err(anyhow::anyhow!("Unsupported pbkdf2 iteration count: {}", c));
+            }
+            let salt = hex::decode(salt.trim_start_matches("0x"))
+                .map_err(|e| anyhow::anyhow!("Invalid keystore salt: {}", e))?;
+            let mut derived_key = Zeroizing::new(vec![0u8; *dklen]);
+            pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, *c, &mut derived_key);
+            Ok(derived_key)
+        }
+    }
+}
@@ -1,10 +1,26 @@
 pub mod wallet;
+pub mod secret;
+pub mod signer;
+pub mod nonce;
+pub mod keystore;
+pub mod vault;
+pub mod slip39;
+pub mod vanity;
+pub mod watch_only;
 pub mod types;
 pub mod utils;
 pub mod handlers;
 pub mod router;
 
 pub use wallet::*;
+pub use secret::*;
+pub use signer::*;
+pub use nonce::*;
+pub use keystore::*;
+pub use vault::*;
+pub use slip39::*;
+pub use vanity::*;
+pub use watch_only::*;
 pub use types::*;
 pub use utils::*;
 pub use handlers::*;
@@ -10,6 +10,11 @@ async fn main() {
         .compact()
         // .with_max_level(tracing::Level::DEBUG)
         .init();
+    if init_vault_from_env().expect("Failed to unlock wallet vault") {
+        info!("Wallet vault enabled");
+    } else {
+        info!("VAULT_MASTER_SECRET not set, wallet vault disabled");
+    }
     let app = create_router();
     let port = env::var("SERVER_PORT")
         .ok()
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use alloy::primitives::Address;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+static NONCE_MANAGER: OnceLock<NonceManager> = OnceLock::new();
+// How long a locally committed nonce may run ahead of the node's pending count. Past this the
+// transaction was most likely dropped, and trusting the node again closes the gap.
+const LOCAL_NONCE_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy)]
+struct TrackedNonce {
+    next: u64,
+    updated_at: Instant,
+}
+
+type NonceSlot = Arc<AsyncMutex<Option<TrackedNonce>>>;
+
+// Hands out nonces per (rpc_url, address). Holding a `NonceLease` serializes every send from that
+// address through this process, so concurrent requests can no longer pick the same pending nonce.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    slots: Mutex<HashMap<(String, Address), NonceSlot>>,
+}
+
+// Exclusive right to allocate the next nonce for one address until it is dropped.
+#[derive(Debug)]
+pub struct NonceLease {
+    next_nonce: OwnedMutexGuard<Option<TrackedNonce>>,
+}
+
+impl NonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn lease(&self, rpc_url: &str, address: Address) -> NonceLease {
+        let slot = {
+            let mut slots = self.slots.lock().unwrap();
+            slots.retain(|_, slot| !Self::is_idle(slot));
+            slots.entry((rpc_url.to_string(), address)).or_default().clone()
+        };
+        NonceLease { next_nonce: slot.lock_owned().await }
+    }
+
+    pub fn slot_count(&self) -> usize {
+        self.slots.lock().unwrap().len()
+    }
+
+    // The RPC URL comes from the request, so slots are evicted once nobody holds or waits for them and
+    // their nonce has aged past the point where `allocate` would trust it anyway.
+    fn is_idle(slot: &NonceSlot) -> bool {
+        if Arc::strong_count(slot) > 1 {
+            return false;
+        }
+        match slot.try_lock() {
+            Ok(tracked) => tracked.is_none_or(|tracked| tracked.updated_at.elapsed() >= LOCAL_NONCE_TTL),
+            Err(_) => false,
+        }
+    }
+}
+
+impl NonceLease {
+    // A load-balanced RPC can report a stale pending count right after a send,
+    // so the locally tracked nonce wins whenever it is ahead.
+    pub fn allocate(&mut self, pending_count: u64) -> u64 {
+        match *self.next_nonce {
+            Some(tracked) if tracked.next > pending_count && tracked.updated_at.elapsed() < LOCAL_NONCE_TTL => tracked.next,
+            _ => pending_count,
+        }
+    }
+
+    pub fn commit(&mut self, nonce: u64) {
+        self.track(nonce + 1);
+    }
+
+    // After a failed send nothing is known about the pool; the next allocation trusts the node again.
+    pub fn invalidate(&mut self) {
+        *self.next_nonce = None;
+    }
+
+    // The node rejected `rejected`, so never hand it out again even if the pending count still says so.
+    pub fn resync(&mut self, pending_count: u64, rejected: u64) -> u64 {
+        let nonce = pending_count.max(rejected + 1);
+        self.track(nonce);
+        nonce
+    }
+
+    pub fn cached(&self) -> Option<u64> {
+        self.next_nonce.map(|tracked| tracked.next)
+    }
+
+    fn track(&mut self, next: u64) {
+        *self.next_nonce = Some(TrackedNonce { next, updated_at: Instant::now() });
+    }
+}
+
+pub fn global_nonce_manager() -> &'static NonceManager {
+    NONCE_MANAGER.get_or_init(NonceManager::new)
+}
+
+// "already known" is deliberately excluded: it means this exact transaction is already in the pool,
+// and re-sending it under a new nonce would pay twice.
+pub fn is_nonce_conflict(error: &str) -> bool {
+    let error = error.to_ascii_lowercase();
+    error.contains("nonce too low")
+        || error.contains("replacement transaction underpriced")
+        || error.contains("nonce has already been used")
+}
@@ -10,11 +10,35 @@ pub fn create_router() -> Router {
     Router::new()
         .route("/health", get(handlers::health_check))
         .route("/wallet/getAddress", post(handlers::address_from_private_key))
+        .route("/wallet/publicKey", post(handlers::get_public_key))
+        .route("/wallet/addressFromPublicKey", post(handlers::address_from_public_key))
+        .route("/wallet/signMessage", post(handlers::sign_message))
+        .route("/wallet/verifyMessage", post(handlers::verify_message))
+        .route("/wallet/signTypedData", post(handlers::sign_typed_data))
+        .route("/wallet/verifyTypedData", post(handlers::verify_typed_data))
         .route("/wallet/generateMnemonic", post(handlers::generate_mnemonic))
         .route("/wallet/generateMnemonicCustom", post(handlers::generate_mnemonic_with_words))
+        .route("/wallet/validateMnemonic", post(handlers::validate_mnemonic))
         .route("/wallet/fromMnemonic", post(handlers::create_wallet_from_mnemonic))
+        .route("/wallet/slip39/split", post(handlers::split_mnemonic_slip39))
+        .route("/wallet/slip39/recover", post(handlers::recover_from_slip39_shares))
+        .route("/wallet/vanity", post(handlers::generate_vanity_address))
+        .route("/wallet/deriveAddresses", post(handlers::derive_addresses))
+        .route("/wallet/xpub/addresses", post(handlers::derive_xpub_addresses))
+        .route("/wallet/keystore/export", post(handlers::export_keystore))
+        .route("/wallet/keystore/import", post(handlers::import_keystore))
+        .route("/vault/create", post(handlers::create_vault_wallet))
+        .route("/vault/list", get(handlers::list_vault_wallets))
+        .route("/vault/delete", post(handlers::delete_vault_wallet))
         .route("/transaction/sendNative", post(handlers::send_native_coin))
         .route("/transaction/sendErc20", post(handlers::send_erc20_token))
+        .route("/transaction/signNative", post(handlers::sign_native_transaction))
+        .route("/transaction/signErc20", post(handlers::sign_erc20_transaction))
+        .route("/transaction/decodeRaw", post(handlers::decode_raw_transaction))
+        .route("/transaction/broadcastRaw", post(handlers::broadcast_raw_transaction))
+        .route("/transaction/speedUp", post(handlers::speed_up_transaction))
+        .route("/transaction/cancel", post(handlers::cancel_transaction))
+        .route("/transaction/sweep", post(handlers::sweep))
         .route("/transaction/estimateGas", post(handlers::estimate_gas))
         .route("/transaction/estimateErc20Gas", post(handlers::estimate_erc20_gas))
         .route("/transaction/receipt", post(handlers::get_transaction_receipt))
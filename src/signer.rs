@@ -0,0 +1,183 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use alloy::consensus::SignableTransaction;
+use alloy::network::TxSigner;
+use alloy::primitives::{Address, Signature, B256};
+use alloy::signers::{local::PrivateKeySigner, utils::public_key_to_address, SignerSync};
+use anyhow::Result;
+use async_trait::async_trait;
+use k256::ecdsa::SigningKey;
+use crate::keystore::{self, Keystore};
+use crate::secret::SecretString;
+use crate::types::{RemoteSignRequest, RemoteSignResponse};
+
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Everything a wallet signs (transactions, EIP-191 messages, EIP-712 data) reduces to a 32-byte hash,
+// so a backend only has to sign hashes.
+#[async_trait]
+pub trait SignerBackend: fmt::Debug + Send + Sync {
+    fn address(&self) -> Address;
+
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature>;
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalSigner {
+    signer: PrivateKeySigner,
+}
+
+impl LocalSigner {
+    pub fn new(signer: PrivateKeySigner) -> Self {
+        LocalSigner { signer }
+    }
+
+    pub fn from_private_key(private_key: &str) -> Result<Self> {
+        let signer = PrivateKeySigner::from_str(private_key)
+            .map_err(|e| anyhow::anyhow!("Failed to create signer from private key: {}", e))?;
+        Ok(Self::new(signer))
+    }
+}
+
+#[async_trait]
+impl SignerBackend for LocalSigner {
+    fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature> {
+        Ok(self.signer.sign_hash_sync(hash)?)
+    }
+}
+
+// The decrypted key only exists for the duration of each signature.
+#[derive(Clone)]
+pub struct KeystoreSigner {
+    keystore: Keystore,
+    password: SecretString,
+    address: Address,
+}
+
+impl KeystoreSigner {
+    pub fn new(keystore: Keystore, password: SecretString) -> Result<Self> {
+        let signing_key = Self::decrypt(&keystore, &password)?;
+        let address = public_key_to_address(signing_key.verifying_key());
+        Ok(KeystoreSigner { keystore, password, address })
+    }
+
+    fn decrypt(keystore: &Keystore, password: &SecretString) -> Result<SigningKey> {
+        let key_bytes = keystore::decrypt_key(keystore, password.expose_secret())?;
+        SigningKey::from_slice(&key_bytes).map_err(|e| anyhow::anyhow!("Invalid key in keystore: {}", e))
+    }
+}
+
+impl fmt::Debug for KeystoreSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeystoreSigner").field("address", &self.address).finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl SignerBackend for KeystoreSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature> {
+        let signer = self.clone();
+        let hash = *hash;
+        // scrypt/pbkdf2 are deliberately slow; keep them off the async workers.
+        tokio::task::spawn_blocking(move || {
+            let signing_key = Self::decrypt(&signer.keystore, &signer.password)?;
+            Ok(PrivateKeySigner::from_signing_key(signing_key).sign_hash_sync(&hash)?)
+        })
+        .await?
+    }
+}
+
+// POSTs `RemoteSignRequest` as JSON to `url` and expects a `RemoteSignResponse`.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    url: String,
+    address: Address,
+    auth_token: Option<SecretString>,
+    client: reqwest::Client,
+}
+
+impl RemoteSigner {
+    pub fn new(url: &str, address: &str, auth_token: Option<SecretString>) -> Result<Self> {
+        let address = crate::utils::parse_address(address)?;
+        let client = reqwest::Client::builder().timeout(REMOTE_SIGNER_TIMEOUT).build()?;
+        Ok(RemoteSigner { url: url.to_string(), address, auth_token, client })
+    }
+}
+
+impl fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("url", &self.url)
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl SignerBackend for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature> {
+        let request = RemoteSignRequest {
+            address: self.address.to_checksum(None),
+            hash: hash.to_string(),
+        };
+        let mut http_request = self.client.post(&self.url).json(&request);
+        if let Some(token) = &self.auth_token {
+            http_request = http_request.bearer_auth(token.expose_secret());
+        }
+        let response = http_request.send().await
+            .map_err(|e| anyhow::anyhow!("Remote signer request failed: {}", e))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("Remote signer returned {}: {}", status, body));
+        }
+        let response: RemoteSignResponse = response.json().await
+            .map_err(|e| anyhow::anyhow!("Invalid remote signer response: {}", e))?;
+
+        let signature = Signature::from_str(&response.signature)
+            .map_err(|e| anyhow::anyhow!("Invalid signature from remote signer: {}", e))?;
+        // 원격 서명자가 다른 키로 서명한 경우 브로드캐스트 전에 차단
+        let recovered = signature.recover_address_from_prehash(hash)
+            .map_err(|e| anyhow::anyhow!("Invalid signature from remote signer: {}", e))?;
+        if recovered != self.address {
+            return Err(anyhow::anyhow!(
+                "Remote signer signed with {} instead of {}",
+                recovered.to_checksum(None),
+                self.address.to_checksum(None)
+            ));
+        }
+        Ok(signature)
+    }
+}
+
+// Lets any backend drive an alloy provider (`EthereumWallet`).
+#[derive(Debug, Clone)]
+pub struct BackendTxSigner(pub Arc<dyn SignerBackend>);
+
+#[async_trait]
+impl TxSigner<Signature> for BackendTxSigner {
+    fn address(&self) -> Address {
+        self.0.address()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> alloy::signers::Result<Signature> {
+        self.0.sign_hash(&tx.signature_hash()).await.map_err(alloy::signers::Error::other)
+    }
+}
@@ -0,0 +1,470 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use anyhow::Result;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::Zeroize;
+use crate::secret::SecretString;
+
+const WORDLIST: &str = include_str!("slip39_wordlist.txt");
+const RADIX_BITS: usize = 10;
+const ID_BITS: usize = 15;
+const METADATA_WORDS: usize = 7;
+const CHECKSUM_WORDS: usize = 3;
+const MIN_SHARE_WORDS: usize = 20;
+const MAX_SHARE_COUNT: usize = 16;
+const DIGEST_LENGTH: usize = 4;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+const ROUND_COUNT: u8 = 4;
+const BASE_ROUND_ITERATIONS: u32 = 2500;
+pub const MAX_ITERATION_EXPONENT: u8 = 15;
+pub const SLIP39_DEFAULT_ITERATION_EXPONENT: u8 = 1;
+
+const RS1024_GEN: [u32; 10] = [
+    0x00e0_e040, 0x01c1_c080, 0x0383_8100, 0x0707_0200, 0x0e0e_0009,
+    0x1c0c_2412, 0x3808_6c24, 0x3090_fc48, 0x21b1_f890, 0x03f3_f120,
+];
+
+const fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        // 3을 곱한 뒤 x^8 + x^4 + x^3 + x + 1 로 reduce
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+        i += 1;
+    }
+    (exp, log)
+}
+
+const GF256_EXP: [u8; 255] = gf256_tables().0;
+const GF256_LOG: [u8; 256] = gf256_tables().1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Drop for Share {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl Share {
+    fn to_mnemonic(&self) -> SecretString {
+        let header: u64 = (u64::from(self.identifier) << 25)
+            | (u64::from(self.extendable) << 24)
+            | (u64::from(self.iteration_exponent) << 20)
+            | (u64::from(self.group_index) << 16)
+            | (u64::from(self.group_threshold - 1) << 12)
+            | (u64::from(self.group_count - 1) << 8)
+            | (u64::from(self.member_index) << 4)
+            | u64::from(self.member_threshold - 1);
+
+        let mut indices: Vec<u16> = (0..4).rev().map(|i| ((header >> (i * RADIX_BITS)) & 1023) as u16).collect();
+        let value_words = (self.value.len() * 8).div_ceil(RADIX_BITS);
+        indices.extend(bytes_to_indices(&self.value, value_words));
+        let checksum = rs1024_create_checksum(customization_string(self.extendable), &indices);
+        indices.extend(checksum);
+
+        let words = wordlist();
+        SecretString::new(indices.iter().map(|i| words[*i as usize]).collect::<Vec<_>>().join(" "))
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Result<Self> {
+        let words = wordlist();
+        let indices = mnemonic
+            .split_whitespace()
+            .map(|word| {
+                let word = word.to_lowercase();
+                words.binary_search(&word.as_str())
+                    .map(|i| i as u16)
+                    .map_err(|_| anyhow::anyhow!("Invalid SLIP-39 word: {}", word))
+            })
+            .collect::<Result<Vec<u16>>>()?;
+
+        if indices.len() < MIN_SHARE_WORDS {
+            return Err(anyhow::anyhow!("Invalid SLIP-39 share length: must be at least {} words", MIN_SHARE_WORDS));
+        }
+        let padding_bits = (RADIX_BITS * (indices.len() - METADATA_WORDS)) % 16;
+        if padding_bits > 8 {
+            return Err(anyhow::anyhow!("Invalid SLIP-39 share length: {} words", indices.len()));
+        }
+
+        let header = indices[..4].iter().fold(0u64, |acc, i| (acc << RADIX_BITS) | u64::from(*i));
+        let identifier = (header >> 25) as u16;
+        let extendable = (header >> 24) & 1 == 1;
+        if !rs1024_verify_checksum(customization_string(extendable), &indices) {
+            return Err(anyhow::anyhow!("Invalid SLIP-39 share checksum"));
+        }
+
+        let group_threshold = ((header >> 12) & 15) as u8 + 1;
+        let group_count = ((header >> 8) & 15) as u8 + 1;
+        if group_threshold > group_count {
+            return Err(anyhow::anyhow!("Invalid SLIP-39 share: group threshold exceeds group count"));
+        }
+
+        let value = indices_to_bytes(&indices[4..indices.len() - CHECKSUM_WORDS], padding_bits)?;
+        Ok(Share {
+            identifier,
+            extendable,
+            iteration_exponent: ((header >> 20) & 15) as u8,
+            group_index: ((header >> 16) & 15) as u8,
+            group_threshold,
+            group_count,
+            member_index: ((header >> 4) & 15) as u8,
+            member_threshold: (header & 15) as u8 + 1,
+            value,
+        })
+    }
+}
+
+pub fn generate_slip39_shares(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[(u8, u8)],
+    iteration_exponent: u8,
+) -> Result<Vec<Vec<SecretString>>> {
+    if master_secret.len() < 16 || !master_secret.len().is_multiple_of(2) {
+        return Err(anyhow::anyhow!("Master secret must be at least 128 bits and an even number of bytes"));
+    }
+    validate_passphrase(passphrase)?;
+    if iteration_exponent > MAX_ITERATION_EXPONENT {
+        return Err(anyhow::anyhow!("Iteration exponent must be at most {}", MAX_ITERATION_EXPONENT));
+    }
+    if groups.is_empty() || groups.len() > MAX_SHARE_COUNT {
+        return Err(anyhow::anyhow!("Group count must be between 1 and {}", MAX_SHARE_COUNT));
+    }
+    if group_threshold == 0 || usize::from(group_threshold) > groups.len() {
+        return Err(anyhow::anyhow!("Group threshold must be between 1 and the number of groups ({})", groups.len()));
+    }
+    for (member_threshold, member_count) in groups {
+        if *member_count == 0 || usize::from(*member_count) > MAX_SHARE_COUNT {
+            return Err(anyhow::anyhow!("Share count must be between 1 and {}", MAX_SHARE_COUNT));
+        }
+        if *member_threshold == 0 || member_threshold > member_count {
+            return Err(anyhow::anyhow!("Threshold must be between 1 and the share count ({})", member_count));
+        }
+        if *member_threshold == 1 && *member_count > 1 {
+            return Err(anyhow::anyhow!("Creating multiple shares with threshold 1 is not allowed. Use 1-of-1 sharing instead"));
+        }
+    }
+
+    let mut id_bytes = [0u8; 2];
+    getrandom::getrandom(&mut id_bytes)?;
+    let identifier = u16::from_be_bytes(id_bytes) & ((1 << ID_BITS) - 1);
+    let extendable = true;
+
+    let encrypted_secret = feistel(master_secret, passphrase, iteration_exponent, identifier, extendable, true);
+    let group_secrets = split_secret(group_threshold, groups.len() as u8, &encrypted_secret)?;
+
+    let mut mnemonics = Vec::with_capacity(groups.len());
+    for ((group_index, group_secret), (member_threshold, member_count)) in group_secrets.iter().zip(groups) {
+        let member_shares = split_secret(*member_threshold, *member_count, group_secret)?;
+        mnemonics.push(
+            member_shares
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Share {
+                        identifier,
+                        extendable,
+                        iteration_exponent,
+                        group_index: *group_index,
+                        group_threshold,
+                        group_count: groups.len() as u8,
+                        member_index,
+                        member_threshold: *member_threshold,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect(),
+        );
+    }
+    Ok(mnemonics)
+}
+
+pub fn combine_slip39_shares(mnemonics: &[SecretString], passphrase: &str) -> Result<Vec<u8>> {
+    combine_slip39_shares_with_limit(mnemonics, passphrase, MAX_ITERATION_EXPONENT)
+}
+
+// Each exponent step doubles the PBKDF2 work of decryption, so callers facing untrusted shares can
+// refuse expensive ones before any key stretching starts.
+pub fn combine_slip39_shares_with_limit(
+    mnemonics: &[SecretString],
+    passphrase: &str,
+    max_iteration_exponent: u8,
+) -> Result<Vec<u8>> {
+    validate_passphrase(passphrase)?;
+    let shares = mnemonics
+        .iter()
+        .map(|mnemonic| Share::from_mnemonic(mnemonic.expose_secret()))
+        .collect::<Result<Vec<Share>>>()?;
+    let first = shares.first().ok_or_else(|| anyhow::anyhow!("No SLIP-39 shares provided"))?;
+    if first.iteration_exponent > max_iteration_exponent {
+        return Err(anyhow::anyhow!(
+            "Unsupported SLIP-39 iteration exponent {}: at most {} is accepted",
+            first.iteration_exponent,
+            max_iteration_exponent
+        ));
+    }
+
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    for share in &shares {
+        if share.identifier != first.identifier || share.extendable != first.extendable {
+            return Err(anyhow::anyhow!("All shares must belong to the same secret (identifier mismatch)"));
+        }
+        if share.iteration_exponent != first.iteration_exponent {
+            return Err(anyhow::anyhow!("All shares must have the same iteration exponent"));
+        }
+        if share.group_threshold != first.group_threshold || share.group_count != first.group_count {
+            return Err(anyhow::anyhow!("All shares must have the same group threshold and group count"));
+        }
+        if share.value.len() != first.value.len() {
+            return Err(anyhow::anyhow!("All shares must have the same length"));
+        }
+
+        let group = groups.entry(share.group_index).or_default();
+        if let Some(existing) = group.first() {
+            if existing.member_threshold != share.member_threshold {
+                return Err(anyhow::anyhow!("Shares of group {} have different thresholds", share.group_index + 1));
+            }
+        }
+        match group.iter().find(|other| other.member_index == share.member_index) {
+            Some(other) if other.value != share.value => {
+                return Err(anyhow::anyhow!("Conflicting shares with the same member index in group {}", share.group_index + 1));
+            }
+            Some(_) => {}
+            None => group.push(share),
+        }
+    }
+
+    let complete_groups: Vec<(u8, Vec<u8>)> = groups
+        .iter()
+        .filter(|(_, members)| members.len() >= usize::from(members[0].member_threshold))
+        .map(|(group_index, members)| {
+            let threshold = members[0].member_threshold;
+            let member_shares: Vec<(u8, Vec<u8>)> = members
+                .iter()
+                .take(usize::from(threshold))
+                .map(|share| (share.member_index, share.value.clone()))
+                .collect();
+            recover_secret(threshold, &member_shares).map(|secret| (*group_index, secret))
+        })
+        .take(usize::from(first.group_threshold))
+        .collect::<Result<_>>()?;
+
+    if complete_groups.len() < usize::from(first.group_threshold) {
+        return Err(anyhow::anyhow!(
+            "Insufficient SLIP-39 shares: {} complete group(s) provided, {} required",
+            complete_groups.len(),
+            first.group_threshold
+        ));
+    }
+
+    let encrypted_secret = recover_secret(first.group_threshold, &complete_groups)?;
+    Ok(feistel(
+        &encrypted_secret,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+        false,
+    ))
+}
+
+fn wordlist() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| WORDLIST.lines().collect())
+}
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+fn validate_passphrase(passphrase: &str) -> Result<()> {
+    if passphrase.bytes().all(|b| (32..=126).contains(&b)) {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!("SLIP-39 passphrase must contain only printable ASCII characters"))
+    }
+}
+
+fn bytes_to_indices(bytes: &[u8], word_count: usize) -> Vec<u16> {
+    let padding_bits = word_count * RADIX_BITS - bytes.len() * 8;
+    let bit = |position: usize| -> u16 {
+        if position < padding_bits {
+            return 0;
+        }
+        let position = position - padding_bits;
+        u16::from((bytes[position / 8] >> (7 - position % 8)) & 1)
+    };
+    (0..word_count)
+        .map(|word| (0..RADIX_BITS).fold(0u16, |acc, i| (acc << 1) | bit(word * RADIX_BITS + i)))
+        .collect()
+}
+
+fn indices_to_bytes(indices: &[u16], padding_bits: usize) -> Result<Vec<u8>> {
+    let bits: Vec<u8> = indices
+        .iter()
+        .flat_map(|index| (0..RADIX_BITS).rev().map(move |i| ((index >> i) & 1) as u8))
+        .collect();
+    if bits[..padding_bits].iter().any(|bit| *bit != 0) {
+        return Err(anyhow::anyhow!("Invalid SLIP-39 share padding"));
+    }
+    Ok(bits[padding_bits..]
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, bit| (acc << 1) | bit))
+        .collect())
+}
+
+fn rs1024_polymod(values: impl Iterator<Item = u32>) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 20;
+        chk = ((chk & 0xfffff) << 10) ^ value;
+        for (i, generator) in RS1024_GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(customization: &[u8], data: &[u16]) -> Vec<u16> {
+    let values = customization
+        .iter()
+        .map(|b| u32::from(*b))
+        .chain(data.iter().map(|i| u32::from(*i)))
+        .chain(std::iter::repeat_n(0, CHECKSUM_WORDS));
+    let polymod = rs1024_polymod(values) ^ 1;
+    (0..CHECKSUM_WORDS)
+        .map(|i| ((polymod >> (RADIX_BITS * (CHECKSUM_WORDS - 1 - i))) & 1023) as u16)
+        .collect()
+}
+
+fn rs1024_verify_checksum(customization: &[u8], data: &[u16]) -> bool {
+    let values = customization
+        .iter()
+        .map(|b| u32::from(*b))
+        .chain(data.iter().map(|i| u32::from(*i)));
+    rs1024_polymod(values) == 1
+}
+
+fn feistel(
+    input: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    encrypt: bool,
+) -> Vec<u8> {
+    let half = input.len() / 2;
+    let mut left = input[..half].to_vec();
+    let mut right = input[half..].to_vec();
+    let salt = if extendable {
+        Vec::new()
+    } else {
+        [b"shamir".as_slice(), &identifier.to_be_bytes()].concat()
+    };
+    let iterations = BASE_ROUND_ITERATIONS << iteration_exponent;
+
+    let rounds: Vec<u8> = if encrypt {
+        (0..ROUND_COUNT).collect()
+    } else {
+        (0..ROUND_COUNT).rev().collect()
+    };
+    for round in rounds {
+        let password = [&[round], passphrase.as_bytes()].concat();
+        let mut round_key = vec![0u8; right.len()];
+        pbkdf2::pbkdf2_hmac::<Sha256>(&password, &[salt.as_slice(), &right].concat(), iterations, &mut round_key);
+        let new_right: Vec<u8> = left.iter().zip(&round_key).map(|(a, b)| a ^ b).collect();
+        left = std::mem::replace(&mut right, new_right);
+    }
+    [right, left].concat()
+}
+
+fn create_digest(random_data: &[u8], shared_secret: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(random_data).expect("HMAC accepts keys of any length");
+    mac.update(shared_secret);
+    mac.finalize().into_bytes()[..DIGEST_LENGTH].to_vec()
+}
+
+fn split_secret(threshold: u8, share_count: u8, secret: &[u8]) -> Result<Vec<(u8, Vec<u8>)>> {
+    if threshold == 1 {
+        return Ok((0..share_count).map(|i| (i, secret.to_vec())).collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares = Vec::with_capacity(usize::from(share_count));
+    for i in 0..random_share_count {
+        let mut value = vec![0u8; secret.len()];
+        getrandom::getrandom(&mut value)?;
+        shares.push((i, value));
+    }
+    let mut random_part = vec![0u8; secret.len() - DIGEST_LENGTH];
+    getrandom::getrandom(&mut random_part)?;
+    let digest = create_digest(&random_part, secret);
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, [digest, random_part].concat()));
+    base_shares.push((SECRET_INDEX, secret.to_vec()));
+    for i in random_share_count..share_count {
+        shares.push((i, interpolate(&base_shares, i)));
+    }
+    Ok(shares)
+}
+
+fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>> {
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    if digest_share[..DIGEST_LENGTH] != create_digest(&digest_share[DIGEST_LENGTH..], &secret)[..] {
+        return Err(anyhow::anyhow!("Invalid SLIP-39 shares: digest verification failed"));
+    }
+    Ok(secret)
+}
+
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Vec<u8> {
+    if let Some((_, value)) = shares.iter().find(|(index, _)| *index == x) {
+        return value.clone();
+    }
+
+    let log = |value: u8| i64::from(GF256_LOG[usize::from(value)]);
+    let log_prod: i64 = shares.iter().map(|(index, _)| log(index ^ x)).sum();
+    let mut result = vec![0u8; shares[0].1.len()];
+    for (index, value) in shares {
+        let others: i64 = shares.iter().map(|(other, _)| log(index ^ other)).sum();
+        let log_basis = (log_prod - log(index ^ x) - others).rem_euclid(255);
+        for (out, byte) in result.iter_mut().zip(value) {
+            if *byte != 0 {
+                *out ^= GF256_EXP[((log(*byte) + log_basis) % 255) as usize];
+            }
+        }
+    }
+    result
+}
@@ -1,36 +1,213 @@
-use serde::{Deserialize, Serialize};
+use alloy::dyn_abi::TypedData;
+use alloy::primitives::U256;
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::keystore::Keystore;
+use crate::secret::SecretString;
+use crate::vault::VaultWalletInfo;
+use crate::wallet::WalletResponse;
+
+// Amounts arrive as decimal strings. JSON integers are exact and still accepted for older clients;
+// any other JSON number has already been rounded through f64, so it is kept only to be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AmountInput {
+    Decimal(String),
+    Inexact(String),
+}
+
+impl AmountInput {
+    pub fn as_decimal(&self) -> anyhow::Result<&str> {
+        match self {
+            AmountInput::Decimal(text) => Ok(text),
+            AmountInput::Inexact(number) => Err(anyhow::anyhow!(
+                "amount {} is a non-integer JSON number and may already be rounded; send it as a decimal string",
+                number
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AmountInput {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Amount {
+            Text(String),
+            Number(serde_json::Number),
+        }
+
+        Ok(match Amount::deserialize(deserializer)? {
+            Amount::Text(text) => AmountInput::Decimal(text),
+            Amount::Number(number) if number.is_u64() || number.is_i64() => AmountInput::Decimal(number.to_string()),
+            Amount::Number(number) => AmountInput::Inexact(number.to_string()),
+        })
+    }
+}
 
 #[derive(Debug, Deserialize)]
 pub struct CreateFromPrivateKeyRequest {
-    pub private_key: String,
+    pub private_key: SecretString,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreateFromMnemonicRequest {
-    pub mnemonic: String,
+    pub mnemonic: SecretString,
+    pub passphrase: Option<SecretString>,
+    pub language: Option<String>,
+    pub account: Option<u32>,
+    pub index: Option<u32>,
+    pub legacy: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PublicKeyRequest {
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddressFromPublicKeyRequest {
+    pub public_key: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignMessageRequest {
+    pub message: String,
+    pub encoding: Option<String>,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyMessageRequest {
+    pub message: String,
+    pub encoding: Option<String>,
+    pub signature: String,
+    pub address: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignTypedDataRequest {
+    pub typed_data: TypedData,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyTypedDataRequest {
+    pub typed_data: TypedData,
+    pub signature: String,
+    pub address: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeriveAddressesRequest {
+    pub mnemonic: SecretString,
+    pub passphrase: Option<SecretString>,
+    pub account: Option<u32>,
+    pub start_index: Option<u32>,
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct XpubAddressesRequest {
+    pub xpub: String,
+    pub start_index: Option<u32>,
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KeystoreExportRequest {
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+    pub password: SecretString,
+    pub kdf: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KeystoreImportRequest {
+    pub keystore: serde_json::Value,
+    pub password: SecretString,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VaultCreateRequest {
+    pub private_key: Option<SecretString>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VaultDeleteRequest {
+    pub wallet_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValidateMnemonicRequest {
+    pub mnemonic: SecretString,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Slip39SplitRequest {
+    pub mnemonic: Option<SecretString>,
+    pub word_count: Option<usize>,
+    pub language: Option<String>,
+    pub threshold: u8,
+    pub share_count: u8,
+    pub passphrase: Option<SecretString>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Slip39RecoverRequest {
+    pub shares: Vec<SecretString>,
+    pub passphrase: Option<SecretString>,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VanityRequest {
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub case_sensitive: Option<bool>,
+    pub threads: Option<usize>,
+    pub timeout_secs: Option<u64>,
+    pub store_in_vault: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct GenerateMnemonicRequest {
     pub word_count: Option<usize>,
+    pub language: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct EstimateGasRequest {
     pub to: String,
-    pub amount: f64,
-    pub private_key: String,
+    pub amount: Option<AmountInput>,
+    pub amount_raw: Option<String>,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
     pub network: Option<String>,
+    pub tx_type: Option<TransactionType>,
+    pub gas_limit: Option<u64>,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct EstimateErc20GasRequest {
     pub to: String,
-    pub amount: f64,
+    pub amount: Option<AmountInput>,
+    pub amount_raw: Option<String>,
     pub token_address: String,
-    pub private_key: String,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
     pub network: Option<String>,
     pub rpc_url: Option<String>,
+    pub tx_type: Option<TransactionType>,
+    pub gas_limit: Option<u64>,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -68,7 +245,103 @@ pub struct ErrorResponse {
 
 #[derive(Debug, Serialize)]
 pub struct MnemonicResponse {
-    pub mnemonic: String,
+    pub mnemonic: SecretString,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DerivedAddress {
+    pub index: u32,
+    pub derivation_path: String,
+    pub address: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeriveAddressesResponse {
+    pub account: u32,
+    pub xpub: String,
+    pub addresses: Vec<DerivedAddress>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct XpubAddressesResponse {
+    pub addresses: Vec<DerivedAddress>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KeystoreExportResponse {
+    pub address: String,
+    pub keystore: Keystore,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultListResponse {
+    pub wallets: Vec<VaultWalletInfo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Slip39SplitResponse {
+    pub address: String,
+    pub threshold: u8,
+    pub share_count: u8,
+    pub shares: Vec<SecretString>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VanityResponse {
+    pub address: String,
+    pub attempts: u64,
+    pub elapsed_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<WalletResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicKeyInfo {
+    pub address: String,
+    pub public_key: String,
+    pub compressed_public_key: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignMessageResponse {
+    pub address: String,
+    pub message_hash: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignTypedDataResponse {
+    pub address: String,
+    pub domain_separator: String,
+    pub struct_hash: String,
+    pub signing_hash: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MessageVerification {
+    pub recovered_address: String,
+    pub expected_address: String,
+    pub is_valid: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnknownMnemonicWord {
+    pub position: usize,
+    pub word: String,
+    pub suggestions: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MnemonicValidation {
+    pub valid: bool,
+    pub word_count: usize,
+    pub word_count_valid: bool,
+    pub language: String,
+    pub unknown_words: Vec<UnknownMnemonicWord>,
+    pub checksum_valid: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -82,23 +355,348 @@ pub struct GasEstimateResponse {
 #[derive(Debug, Deserialize)]
 pub struct SendTransactionRequest {
     pub to: String,
-    pub amount: f64,
-    pub private_key: String,
+    pub amount: Option<AmountInput>,
+    pub amount_raw: Option<String>,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+    pub network: Option<String>,
+    pub tx_type: Option<TransactionType>,
+    pub gas_limit: Option<u64>,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
+    pub nonce: Option<u64>,
+    pub confirmations: Option<u64>,
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SweepRequest {
+    pub to: String,
+    pub token_addresses: Option<Vec<String>>,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
     pub network: Option<String>,
+    pub tx_type: Option<TransactionType>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct SendErc20Request {
     pub to: String,
-    pub amount: f64,
+    pub amount: Option<AmountInput>,
+    pub amount_raw: Option<String>,
+    pub token_address: String,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+    pub network: Option<String>,
+    pub tx_type: Option<TransactionType>,
+    pub gas_limit: Option<u64>,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
+    pub nonce: Option<u64>,
+    pub confirmations: Option<u64>,
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+}
+
+// Accepts the names as well as the EIP-2718 type numbers, either as strings or as JSON integers.
+impl<'de> Deserialize<'de> for TransactionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum TxType {
+            Text(String),
+            Number(u64),
+        }
+
+        let value = match TxType::deserialize(deserializer)? {
+            TxType::Text(text) => text,
+            TxType::Number(number) => number.to_string(),
+        };
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl TransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Legacy => "legacy",
+            TransactionType::Eip2930 => "eip2930",
+            TransactionType::Eip1559 => "eip1559",
+        }
+    }
+}
+
+impl std::str::FromStr for TransactionType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "legacy" | "0" => Ok(TransactionType::Legacy),
+            "eip2930" | "2930" | "1" => Ok(TransactionType::Eip2930),
+            "eip1559" | "1559" | "2" => Ok(TransactionType::Eip1559),
+            other => Err(anyhow::anyhow!("Unsupported transaction type: {} (expected legacy, eip2930 or eip1559)", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TxOptions {
+    pub tx_type: Option<TransactionType>,
+    pub gas_limit: Option<u64>,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
+    pub nonce: Option<u64>,
+}
+
+impl TxOptions {
+    // Fee overrides imply a type when none is requested: `gas_price` means legacy, the EIP-1559 fields mean EIP-1559.
+    pub fn requested_transaction_type(&self) -> Option<TransactionType> {
+        if self.tx_type.is_some() {
+            self.tx_type
+        } else if self.gas_price.is_some() {
+            Some(TransactionType::Legacy)
+        } else if self.max_fee_per_gas.is_some() || self.max_priority_fee_per_gas.is_some() {
+            Some(TransactionType::Eip1559)
+        } else {
+            None
+        }
+    }
+}
+
+// Upper bounds for caller-supplied overrides, from MAX_GAS_LIMIT, MAX_FEE_PER_GAS_WEI,
+// MAX_PRIORITY_FEE_PER_GAS_WEI and MAX_GAS_PRICE_WEI.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeCaps {
+    pub max_gas_limit: u64,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub max_gas_price: u128,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OfflineTxParams {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
+    pub tx_type: Option<TransactionType>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignNativeRequest {
+    pub to: String,
+    pub amount_raw: String,
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
+    pub tx_type: Option<TransactionType>,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignErc20Request {
+    pub to: String,
+    pub amount_raw: String,
     pub token_address: String,
-    pub private_key: String,
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    pub max_fee_per_gas: Option<u128>,
+    pub max_priority_fee_per_gas: Option<u128>,
+    pub gas_price: Option<u128>,
+    pub tx_type: Option<TransactionType>,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignedTransaction {
+    pub from: String,
+    pub to: String,
+    pub nonce: u64,
+    pub chain_id: u64,
+    pub tx_type: String,
+    pub raw_transaction: String,
+    pub tx_hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RawTransactionRequest {
+    pub raw_transaction: String,
     pub network: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct DecodedErc20Transfer {
+    pub token_address: String,
+    pub to: String,
+    pub amount: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DecodedTransaction {
+    pub tx_type: String,
+    pub tx_hash: String,
+    pub from: String,
+    pub to: Option<String>,
+    pub value: String,
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    pub gas_limit: u64,
+    pub gas_price: Option<String>,
+    pub max_fee_per_gas: Option<String>,
+    pub max_priority_fee_per_gas: Option<String>,
+    pub input: String,
+    pub erc20_transfer: Option<DecodedErc20Transfer>,
+    pub authorization_count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReplaceTransactionRequest {
+    pub tx_hash: String,
+    pub private_key: Option<SecretString>,
+    pub wallet_id: Option<String>,
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplacementKind {
+    SpeedUp,
+    Cancel,
+}
+
+impl ReplacementKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReplacementKind::SpeedUp => "speed_up",
+            ReplacementKind::Cancel => "cancel",
+        }
+    }
+}
+
+// Why a replacement was refused, so the handler can answer 400, 404 or 409 instead of a blanket 500.
+#[derive(Debug)]
+pub enum ReplacementError {
+    InvalidHash(String),
+    NotFound(String),
+    NotReplaceable(String),
+    Failed(anyhow::Error),
+}
+
+impl std::fmt::Display for ReplacementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplacementError::InvalidHash(reason) => write!(f, "Invalid transaction hash: {}", reason),
+            ReplacementError::NotFound(hash) => write!(f, "Transaction {} not found", hash),
+            ReplacementError::NotReplaceable(reason) => write!(f, "{}", reason),
+            ReplacementError::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReplacementError {}
+
+impl From<anyhow::Error> for ReplacementError {
+    fn from(e: anyhow::Error) -> Self {
+        ReplacementError::Failed(e)
+    }
+}
+
+impl From<alloy::transports::TransportError> for ReplacementError {
+    fn from(e: alloy::transports::TransportError) -> Self {
+        ReplacementError::Failed(e.into())
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReplacementTransaction {
+    pub action: String,
+    pub original_hash: String,
+    pub hash: String,
+    pub from: String,
+    pub nonce: u64,
+    pub tx_type: String,
+    pub gas_price: Option<String>,
+    pub max_fee_per_gas: Option<String>,
+    pub max_priority_fee_per_gas: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BroadcastRawResponse {
+    pub hash: String,
+    pub transaction: DecodedTransaction,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteSignRequest {
+    pub address: String,
+    pub hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteSignResponse {
+    pub signature: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct TransactionResponse {
     pub hash: String,
+    // "pending" when the request did not wait, otherwise "success" or "reverted".
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_used: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_gas_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_fee: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfirmedTransaction {
+    pub block_number: u64,
+    pub confirmations: u64,
+    pub gas_used: u64,
+    pub effective_gas_price: u128,
+    pub transaction_fee: U256,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SweepTokenTransfer {
+    pub token_address: String,
+    pub amount: String,
+    pub tx_hash: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SweepResult {
+    pub from: String,
+    pub to: String,
+    pub token_transfers: Vec<SweepTokenTransfer>,
+    pub native_amount: String,
+    pub native_fee: String,
+    pub native_tx_hash: Option<String>,
+    pub tx_hashes: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
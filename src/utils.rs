@@ -1,7 +1,9 @@
 use std::env;
+use bip39::Language;
 use alloy::primitives::{U256, Address, Bytes};
 use alloy::providers::{Provider, ProviderBuilder};
-use alloy::rpc::types::TransactionRequest;
+use alloy::rpc::types::{AccessList, TransactionRequest};
+use crate::types::{FeeCaps, TransactionType, TxOptions};
 use std::str::FromStr;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -14,14 +16,245 @@ pub fn get_rpc_url_for_network(network: Option<&str>) -> String {
     }
 }
 
+pub fn parse_mnemonic_language(name: &str) -> anyhow::Result<Language> {
+    match name.trim().to_lowercase().replace('-', "_").as_str() {
+        "english" | "en" => Ok(Language::English),
+        "korean" | "ko" => Ok(Language::Korean),
+        "japanese" | "ja" => Ok(Language::Japanese),
+        "spanish" | "es" => Ok(Language::Spanish),
+        "chinese_simplified" | "chinese" | "zh_cn" | "zh_hans" => Ok(Language::SimplifiedChinese),
+        "chinese_traditional" | "zh_tw" | "zh_hant" => Ok(Language::TraditionalChinese),
+        "french" | "fr" => Ok(Language::French),
+        "italian" | "it" => Ok(Language::Italian),
+        "czech" | "cs" => Ok(Language::Czech),
+        _ => Err(anyhow::anyhow!(
+            "Unsupported mnemonic language: {}. Supported: english, korean, japanese, spanish, chinese_simplified, chinese_traditional, french, italian, czech",
+            name
+        )),
+    }
+}
+
+pub fn mnemonic_language_name(language: Language) -> &'static str {
+    match language {
+        Language::English => "english",
+        Language::Korean => "korean",
+        Language::Japanese => "japanese",
+        Language::Spanish => "spanish",
+        Language::SimplifiedChinese => "chinese_simplified",
+        Language::TraditionalChinese => "chinese_traditional",
+        Language::French => "french",
+        Language::Italian => "italian",
+        Language::Czech => "czech",
+    }
+}
+
+pub fn is_strict_address_checksum() -> bool {
+    matches!(
+        env::var("STRICT_ADDRESS_CHECKSUM").unwrap_or_default().to_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+pub fn parse_address(input: &str) -> anyhow::Result<Address> {
+    if is_strict_address_checksum() {
+        parse_address_strict(input)
+    } else {
+        Address::from_str(input.trim()).map_err(|e| anyhow::anyhow!("Invalid address {}: {}", input, e))
+    }
+}
+
+// EIP-55: all-lowercase or all-uppercase hex carries no checksum; mixed case must match it exactly.
+pub fn parse_address_strict(input: &str) -> anyhow::Result<Address> {
+    let input = input.trim();
+    let hex_part = input.strip_prefix("0x").ok_or_else(|| anyhow::anyhow!("Invalid address {}: missing 0x prefix", input))?;
+    let address = Address::from_str(input).map_err(|e| anyhow::anyhow!("Invalid address {}: {}", input, e))?;
+    let has_lower = hex_part.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex_part.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper && address.to_checksum(None)[2..] != *hex_part {
+        return Err(anyhow::anyhow!(
+            "Invalid address checksum for {}: expected {}",
+            input,
+            address.to_checksum(None)
+        ));
+    }
+    Ok(address)
+}
+
+// personal_sign 메시지는 기본적으로 UTF-8, "hex" 지정 시 0x 바이트열로 해석
+pub fn decode_message(message: &str, encoding: Option<&str>) -> anyhow::Result<Vec<u8>> {
+    match encoding.map(|e| e.to_lowercase()).as_deref() {
+        None | Some("utf8") | Some("utf-8") => Ok(message.as_bytes().to_vec()),
+        Some("hex") => hex::decode(message.trim().trim_start_matches("0x"))
+            .map_err(|e| anyhow::anyhow!("Invalid hex message: {}", e)),
+        Some(other) => Err(anyhow::anyhow!("Unsupported message encoding: {} (expected utf8 or hex)", other)),
+    }
+}
+
 pub fn is_very_network(rpc_url: &str) -> bool {
     rpc_url.contains("verylabs.io") || rpc_url.contains("very")
 }
 
-pub fn is_non_eip1559_network(rpc_url: &str) -> bool {
+// Rollups that bill an L1 data fee on top of the L2 gas, which no fee estimate here accounts for.
+pub fn charges_l1_data_fee(rpc_url: &str) -> bool {
     let rpc_lower = rpc_url.to_lowercase();
-    rpc_lower.contains("bsc") || 
-    rpc_lower.contains("binance")
+    rpc_lower.contains("arbitrum") || rpc_lower.contains("optimism")
+}
+
+pub fn configured_transaction_type() -> Option<TransactionType> {
+    let value = env::var("TX_TYPE").ok().filter(|v| !v.trim().is_empty())?;
+    match TransactionType::from_str(&value) {
+        Ok(tx_type) => Some(tx_type),
+        Err(e) => {
+            warn!("Ignoring TX_TYPE: {}", e);
+            None
+        }
+    }
+}
+
+pub const DEFAULT_MAX_GAS_LIMIT: u64 = 30_000_000;
+pub const DEFAULT_MAX_FEE_WEI: u128 = 1_000_000_000_000;
+
+fn env_cap<T: FromStr + std::fmt::Display + Copy>(name: &str, default: T) -> T {
+    match env::var(name) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().unwrap_or_else(|_| {
+            warn!("Ignoring invalid {}={}, using {}", name, value, default);
+            default
+        }),
+        _ => default,
+    }
+}
+
+pub fn configured_fee_caps() -> FeeCaps {
+    FeeCaps {
+        max_gas_limit: env_cap("MAX_GAS_LIMIT", DEFAULT_MAX_GAS_LIMIT),
+        max_fee_per_gas: env_cap("MAX_FEE_PER_GAS_WEI", DEFAULT_MAX_FEE_WEI),
+        max_priority_fee_per_gas: env_cap("MAX_PRIORITY_FEE_PER_GAS_WEI", DEFAULT_MAX_FEE_WEI),
+        max_gas_price: env_cap("MAX_GAS_PRICE_WEI", DEFAULT_MAX_FEE_WEI),
+    }
+}
+
+pub const DEFAULT_SLIP39_MAX_ITERATION_EXPONENT: u8 = 5;
+
+// The spec allows exponents up to 15 (~330M PBKDF2 rounds), far too much work to accept from a request.
+pub fn configured_slip39_max_iteration_exponent() -> u8 {
+    env_cap("SLIP39_MAX_ITERATION_EXPONENT", DEFAULT_SLIP39_MAX_ITERATION_EXPONENT).min(crate::slip39::MAX_ITERATION_EXPONENT)
+}
+
+pub const DEFAULT_MAX_CONCURRENT_KEYSTORE_KDF: usize = 2;
+
+// Each scrypt derivation can hold up to 256 MiB, so only this many keystore requests derive keys at once.
+pub fn configured_max_concurrent_keystore_kdf() -> usize {
+    env_cap("MAX_CONCURRENT_KEYSTORE_KDF", DEFAULT_MAX_CONCURRENT_KEYSTORE_KDF).max(1)
+}
+
+pub const DEFAULT_MAX_CONCURRENT_VANITY_SEARCHES: usize = 1;
+
+// A search already spreads over every CPU, so further searches only slow each other down.
+pub fn configured_max_concurrent_vanity_searches() -> usize {
+    env_cap("MAX_CONCURRENT_VANITY_SEARCHES", DEFAULT_MAX_CONCURRENT_VANITY_SEARCHES).max(1)
+}
+
+pub fn validate_tx_options(options: &TxOptions, caps: &FeeCaps) -> anyhow::Result<()> {
+    let has_eip1559_fees = options.max_fee_per_gas.is_some() || options.max_priority_fee_per_gas.is_some();
+    if options.gas_price.is_some() && has_eip1559_fees {
+        return Err(anyhow::anyhow!("gas_price cannot be combined with max_fee_per_gas or max_priority_fee_per_gas"));
+    }
+    match options.tx_type {
+        Some(TransactionType::Eip1559) if options.gas_price.is_some() => {
+            return Err(anyhow::anyhow!("eip1559 transactions take max_fee_per_gas and max_priority_fee_per_gas, not gas_price"));
+        }
+        Some(tx_type @ (TransactionType::Legacy | TransactionType::Eip2930)) if has_eip1559_fees => {
+            return Err(anyhow::anyhow!("{} transactions take gas_price, not max_fee_per_gas or max_priority_fee_per_gas", tx_type.as_str()));
+        }
+        _ => {}
+    }
+
+    if let Some(gas_limit) = options.gas_limit {
+        if gas_limit < 21_000 || gas_limit > caps.max_gas_limit {
+            return Err(anyhow::anyhow!("gas_limit must be between 21000 and {} (MAX_GAS_LIMIT)", caps.max_gas_limit));
+        }
+    }
+    if let Some(max_fee_per_gas) = options.max_fee_per_gas {
+        if max_fee_per_gas == 0 || max_fee_per_gas > caps.max_fee_per_gas {
+            return Err(anyhow::anyhow!("max_fee_per_gas must be between 1 and {} wei (MAX_FEE_PER_GAS_WEI)", caps.max_fee_per_gas));
+        }
+    }
+    if let Some(max_priority_fee_per_gas) = options.max_priority_fee_per_gas {
+        if max_priority_fee_per_gas > caps.max_priority_fee_per_gas {
+            return Err(anyhow::anyhow!("max_priority_fee_per_gas must be at most {} wei (MAX_PRIORITY_FEE_PER_GAS_WEI)", caps.max_priority_fee_per_gas));
+        }
+        if options.max_fee_per_gas.is_some_and(|max_fee_per_gas| max_priority_fee_per_gas > max_fee_per_gas) {
+            return Err(anyhow::anyhow!("max_priority_fee_per_gas must not exceed max_fee_per_gas"));
+        }
+    }
+    if let Some(gas_price) = options.gas_price {
+        if gas_price == 0 || gas_price > caps.max_gas_price {
+            return Err(anyhow::anyhow!("gas_price must be between 1 and {} wei (MAX_GAS_PRICE_WEI)", caps.max_gas_price));
+        }
+    }
+    Ok(())
+}
+
+// 우선순위: 요청 값 > TX_TYPE 환경변수 > 최신 블록의 base fee 유무로 자동 판별
+pub async fn resolve_transaction_type(requested: Option<TransactionType>, rpc_url: &str) -> TransactionType {
+    if let Some(tx_type) = requested.or_else(configured_transaction_type) {
+        return tx_type;
+    }
+
+    let provider = match rpc_url.parse() {
+        Ok(url) => ProviderBuilder::new().connect_http(url),
+        Err(_) => return TransactionType::Eip1559,
+    };
+    match provider.get_block_by_number(alloy::eips::BlockNumberOrTag::Latest).await {
+        Ok(Some(block)) if block.header.base_fee_per_gas.is_none() => {
+            info!("Latest block has no base fee, using legacy transactions");
+            TransactionType::Legacy
+        }
+        Ok(_) => TransactionType::Eip1559,
+        Err(e) => {
+            warn!("Failed to detect transaction type, defaulting to EIP-1559: {}", e);
+            TransactionType::Eip1559
+        }
+    }
+}
+
+// Fee values can come from an untrusted RPC (e.g. a doubled gas price), so anything past u128 is
+// an error rather than a panic in the request task.
+pub fn apply_fee_fields(
+    tx: TransactionRequest,
+    tx_type: TransactionType,
+    max_fee_per_gas: U256,
+    max_priority_fee_per_gas: U256,
+    gas_price: U256,
+) -> anyhow::Result<TransactionRequest> {
+    let fee = |name: &str, value: U256| {
+        u128::try_from(value).map_err(|_| anyhow::anyhow!("{} of {} wei is out of range", name, value))
+    };
+    Ok(match tx_type {
+        TransactionType::Legacy => tx.transaction_type(0).gas_price(fee("gas_price", gas_price)?),
+        TransactionType::Eip2930 => tx
+            .transaction_type(1)
+            .gas_price(fee("gas_price", gas_price)?)
+            .access_list(AccessList::default()),
+        TransactionType::Eip1559 => tx
+            .transaction_type(2)
+            .max_fee_per_gas(fee("max_fee_per_gas", max_fee_per_gas)?)
+            .max_priority_fee_per_gas(fee("max_priority_fee_per_gas", max_priority_fee_per_gas)?),
+    })
+}
+
+// Nodes reject a same-nonce replacement unless every fee rises by their price bump
+// (geth: 10%, several other clients: 12.5%). Bumping by 12.5%, rounded up, satisfies all of them.
+pub fn bump_replacement_fee(fee: u128) -> u128 {
+    fee.saturating_add(fee.div_ceil(8)).max(fee.saturating_add(1))
+}
+
+// 레거시/2930은 gas_price 그대로, 1559는 base + priority 가 실제 지불 단가
+pub fn effective_gas_price(tx_type: TransactionType, base_fee: U256, priority_fee: U256) -> U256 {
+    match tx_type {
+        TransactionType::Legacy | TransactionType::Eip2930 => base_fee,
+        TransactionType::Eip1559 => base_fee + priority_fee,
+    }
 }
 
 pub async fn get_dynamic_gas_price(rpc_url: &str) -> Result<U256, Box<dyn std::error::Error>> {
@@ -120,9 +353,7 @@ pub async fn get_eip1559_gas_price(rpc_url: &str) -> (U256, U256, U256) {
         return (max_fee, priority_fee, base_price);
     }
     
-    let provider = match ProviderBuilder::new().connect_http(rpc_url.parse().ok().unwrap_or_else(|| "http://localhost:8545".parse().unwrap())) {
-        provider => provider
-    };
+    let provider = ProviderBuilder::new().connect_http(rpc_url.parse().ok().unwrap_or_else(|| "http://localhost:8545".parse().unwrap()));
     
     let base_price = match provider.get_gas_price().await {
         Ok(price) => U256::from(price),
@@ -165,10 +396,8 @@ fn calculate_priority_fee(rpc_url: &str, base_price: U256) -> U256 {
     // 매우 낮은 최소값만 설정 (하드코딩 최소화)
     let min_priority = if rpc_lower.contains("arbitrum") || rpc_lower.contains("optimism") {
         U256::from(1_000_000u64) // 0.001 Gwei (L2는 매우 낮게)
-    } else if rpc_lower.contains("polygon") {
-        U256::from(10_000_000u64) // 0.01 Gwei
     } else {
-        U256::from(10_000_000u64) // 0.01 Gwei (기본값)
+        U256::from(10_000_000u64) // 0.01 Gwei (Polygon 포함 기본값)
     };
     
     // 계산된 값 우선, 최소값은 안전장치로만
@@ -216,7 +445,7 @@ pub fn wei_to_eth(wei: U256) -> String {
     let len = wei_str.len();
     
     let eth_str = if len <= 18 {
-        format!("0.{}", format!("{:0>18}", wei_str))
+        format!("0.{:0>18}", wei_str)
     } else {
         let (integer_part, fractional_part) = wei_str.split_at(len - 18);
         format!("{}.{}", integer_part, fractional_part)
@@ -229,11 +458,10 @@ pub async fn get_token_decimals(token_address: &str, rpc_url: &str) -> Result<u8
     let provider = ProviderBuilder::new()
         .connect_http(rpc_url.parse()?);
     
-    let token_addr = Address::from_str(token_address)?;
+    let token_addr = parse_address(token_address)?;
     
     let function_selector = "313ce567";
-    let data = format!("{}", function_selector);
-    let call_data = Bytes::from(hex::decode(data)?);
+    let call_data = Bytes::from(hex::decode(function_selector)?);
 
     let call_request = TransactionRequest::default()
         .to(token_addr)
@@ -244,13 +472,58 @@ pub async fn get_token_decimals(token_address: &str, rpc_url: &str) -> Result<u8
     Ok(decimals)
 }
 
+pub fn parse_raw_amount(amount: &str) -> anyhow::Result<U256> {
+    parse_decimal_amount(amount, 0)
+}
+
+// "1.5" with 6 decimals -> 1500000, computed on integers only so nothing is lost to floating point.
+pub fn parse_decimal_amount(amount: &str, decimals: u8) -> anyhow::Result<U256> {
+    let amount = amount.trim();
+    if amount.starts_with('-') {
+        return Err(anyhow::anyhow!("Invalid amount {}: must not be negative", amount));
+    }
+    let (integer_part, fractional_part) = match amount.split_once('.') {
+        Some((integer_part, fractional_part)) => (integer_part, fractional_part),
+        None => (amount, ""),
+    };
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if integer_part.is_empty()
+        || !is_digits(integer_part)
+        || !is_digits(fractional_part)
+        || (amount.contains('.') && fractional_part.is_empty())
+    {
+        return Err(anyhow::anyhow!("Invalid amount {:?}: expected a plain decimal such as \"1.5\"", amount));
+    }
+    if fractional_part.len() > decimals as usize {
+        return Err(anyhow::anyhow!(
+            "Invalid amount {}: at most {} fractional digits are allowed",
+            amount,
+            decimals
+        ));
+    }
+
+    let overflow = || anyhow::anyhow!("Invalid amount {}: too large", amount);
+    let scale = U256::from(10u64).checked_pow(U256::from(decimals)).ok_or_else(overflow)?;
+    let fraction_scale = U256::from(10u64).pow(U256::from(decimals as usize - fractional_part.len()));
+    let integer = U256::from_str_radix(integer_part, 10).map_err(|_| overflow())?;
+    let fraction = if fractional_part.is_empty() {
+        U256::ZERO
+    } else {
+        U256::from_str_radix(fractional_part, 10).map_err(|_| overflow())?
+    };
+    integer
+        .checked_mul(scale)
+        .and_then(|value| value.checked_add(fraction * fraction_scale))
+        .ok_or_else(overflow)
+}
+
 pub fn token_amount_to_readable(amount: U256, decimals: u8) -> String {
     let amount_str = amount.to_string();
     let len = amount_str.len();
     let decimal_places = decimals as usize;
     
     let readable_str = if len <= decimal_places {
-        format!("0.{}", format!("{:0>width$}", amount_str, width = decimal_places))
+        format!("0.{:0>width$}", amount_str, width = decimal_places)
     } else {
         let (integer_part, fractional_part) = amount_str.split_at(len - decimal_places);
         format!("{}.{}", integer_part, fractional_part)
@@ -0,0 +1,188 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use alloy::signers::utils::public_key_to_address;
+use anyhow::Result;
+use k256::ecdsa::SigningKey;
+use crate::wallet::EvmWallet;
+
+pub const MAX_VANITY_PATTERN_LENGTH: usize = 40;
+pub const MAX_VANITY_TIMEOUT: Duration = Duration::from_secs(600);
+pub const MAX_VANITY_THREADS: usize = 64;
+const ATTEMPTS_PER_BATCH: u64 = 256;
+
+#[derive(Debug, Clone)]
+pub struct VanityOptions {
+    pub prefix: String,
+    pub suffix: String,
+    pub case_sensitive: bool,
+    pub threads: usize,
+    pub timeout: Duration,
+    pub progress_interval: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct VanityProgress {
+    pub attempts: u64,
+    pub elapsed: Duration,
+    pub expected_attempts: f64,
+}
+
+impl VanityProgress {
+    pub fn rate(&self) -> f64 {
+        self.attempts as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+#[derive(Debug)]
+pub struct VanityResult {
+    pub wallet: EvmWallet,
+    pub attempts: u64,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub enum VanityError {
+    Invalid(String),
+    TimedOut(String),
+    Failed(anyhow::Error),
+}
+
+impl std::fmt::Display for VanityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VanityError::Invalid(reason) | VanityError::TimedOut(reason) => write!(f, "{}", reason),
+            VanityError::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for VanityError {}
+
+impl VanityOptions {
+    pub fn new(prefix: &str, suffix: &str) -> Self {
+        VanityOptions {
+            prefix: prefix.trim_start_matches("0x").to_string(),
+            suffix: suffix.to_string(),
+            case_sensitive: true,
+            threads: available_threads(),
+            timeout: Duration::from_secs(60),
+            progress_interval: Duration::from_secs(5),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.prefix.is_empty() && self.suffix.is_empty() {
+            return Err(anyhow::anyhow!("A prefix or suffix is required"));
+        }
+        if self.prefix.len() + self.suffix.len() > MAX_VANITY_PATTERN_LENGTH {
+            return Err(anyhow::anyhow!("Prefix and suffix together must be at most {} characters", MAX_VANITY_PATTERN_LENGTH));
+        }
+        if let Some(c) = self.prefix.chars().chain(self.suffix.chars()).find(|c| !c.is_ascii_hexdigit()) {
+            return Err(anyhow::anyhow!("Invalid character '{}' in vanity pattern: only hex digits are allowed", c));
+        }
+        if self.threads == 0 || self.threads > MAX_VANITY_THREADS {
+            return Err(anyhow::anyhow!("threads must be between 1 and {}", MAX_VANITY_THREADS));
+        }
+        if self.timeout.is_zero() || self.timeout > MAX_VANITY_TIMEOUT {
+            return Err(anyhow::anyhow!("timeout must be between 1 and {} seconds", MAX_VANITY_TIMEOUT.as_secs()));
+        }
+        Ok(())
+    }
+
+    // Every hex character is a 1-in-16 match; in case-sensitive mode each letter also has
+    // to land on the right EIP-55 case, which is another 1-in-2.
+    pub fn expected_attempts(&self) -> f64 {
+        let pattern = self.prefix.chars().chain(self.suffix.chars());
+        pattern.fold(1.0, |acc, c| {
+            if self.case_sensitive && c.is_ascii_alphabetic() {
+                acc * 32.0
+            } else {
+                acc * 16.0
+            }
+        })
+    }
+
+    fn matches(&self, signing_key: &SigningKey) -> bool {
+        let address = public_key_to_address(signing_key.verifying_key());
+        let lower = hex::encode(address);
+        if !lower.starts_with(&self.prefix.to_ascii_lowercase()) || !lower.ends_with(&self.suffix.to_ascii_lowercase()) {
+            return false;
+        }
+        if !self.case_sensitive {
+            return true;
+        }
+        let checksummed = address.to_checksum(None);
+        let checksummed = checksummed.trim_start_matches("0x");
+        checksummed.starts_with(&self.prefix) && checksummed.ends_with(&self.suffix)
+    }
+}
+
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1).min(MAX_VANITY_THREADS)
+}
+
+pub fn search_vanity_address(
+    options: &VanityOptions,
+    mut on_progress: impl FnMut(&VanityProgress),
+) -> std::result::Result<VanityResult, VanityError> {
+    options.validate().map_err(|e| VanityError::Invalid(e.to_string()))?;
+
+    let started = Instant::now();
+    let deadline = started + options.timeout;
+    let expected_attempts = options.expected_attempts();
+    let attempts = AtomicU64::new(0);
+    let stop = AtomicBool::new(false);
+    let found: Mutex<Option<SigningKey>> = Mutex::new(None);
+
+    thread::scope(|scope| {
+        for _ in 0..options.threads {
+            scope.spawn(|| {
+                while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+                    let mut batch_attempts = 0;
+                    while batch_attempts < ATTEMPTS_PER_BATCH {
+                        batch_attempts += 1;
+                        let signing_key = EvmWallet::random_signing_key();
+                        if options.matches(&signing_key) {
+                            found.lock().unwrap().get_or_insert(signing_key);
+                            stop.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                    }
+                    attempts.fetch_add(batch_attempts, Ordering::Relaxed);
+                }
+            });
+        }
+
+        let mut next_report = started + options.progress_interval;
+        while !stop.load(Ordering::Relaxed) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(50));
+            if Instant::now() >= next_report {
+                on_progress(&VanityProgress {
+                    attempts: attempts.load(Ordering::Relaxed),
+                    elapsed: started.elapsed(),
+                    expected_attempts,
+                });
+                next_report += options.progress_interval;
+            }
+        }
+        stop.store(true, Ordering::Relaxed);
+    });
+
+    let attempts = attempts.load(Ordering::Relaxed);
+    let elapsed = started.elapsed();
+    match found.into_inner().unwrap() {
+        Some(signing_key) => Ok(VanityResult {
+            wallet: EvmWallet::from_signing_key(&signing_key, None, None).map_err(VanityError::Failed)?,
+            attempts,
+            elapsed,
+        }),
+        None => Err(VanityError::TimedOut(format!(
+            "No matching address found after {} attempts in {:.1}s (about {:.0} attempts expected)",
+            attempts,
+            elapsed.as_secs_f64(),
+            expected_attempts
+        ))),
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+use crate::keystore::{Keystore, KeystoreKdf};
+use crate::secret::SecretString;
+use crate::wallet::EvmWallet;
+
+static VAULT: OnceLock<WalletVault> = OnceLock::new();
+static VAULT_API_TOKEN: OnceLock<SecretString> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultWalletInfo {
+    pub wallet_id: String,
+    pub address: String,
+    pub created_at: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VaultEntry {
+    wallet_id: String,
+    address: String,
+    created_at: u64,
+    keystore: Keystore,
+}
+
+#[derive(Debug)]
+pub struct WalletVault {
+    dir: PathBuf,
+    master_secret: SecretString,
+    kdf: KeystoreKdf,
+    wallets: RwLock<HashMap<String, (VaultWalletInfo, EvmWallet)>>,
+}
+
+impl WalletVault {
+    pub fn open(dir: impl Into<PathBuf>, master_secret: &str) -> Result<Self> {
+        Self::open_with_kdf(dir, master_secret, KeystoreKdf::Scrypt { n: 16_384, r: 8, p: 1 })
+    }
+
+    pub fn open_with_kdf(dir: impl Into<PathBuf>, master_secret: &str, kdf: KeystoreKdf) -> Result<Self> {
+        if master_secret.is_empty() {
+            return Err(anyhow::anyhow!("Vault master secret must not be empty"));
+        }
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .map_err(|e| anyhow::anyhow!("Failed to create vault directory {}: {}", dir.display(), e))?;
+
+        let mut wallets = HashMap::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let contents = fs::read_to_string(&path)?;
+            let vault_entry: VaultEntry = serde_json::from_str(&contents)
+                .map_err(|e| anyhow::anyhow!("Invalid vault entry {}: {}", path.display(), e))?;
+            let wallet = EvmWallet::from_keystore(&vault_entry.keystore, master_secret)
+                .map_err(|e| anyhow::anyhow!("Failed to unlock vault entry {}: {}", vault_entry.wallet_id, e))?;
+            let info = VaultWalletInfo {
+                wallet_id: vault_entry.wallet_id.clone(),
+                address: wallet.address.clone(),
+                created_at: vault_entry.created_at,
+            };
+            wallets.insert(vault_entry.wallet_id, (info, wallet));
+        }
+        info!("Wallet vault unlocked: {} wallet(s) in {}", wallets.len(), dir.display());
+
+        Ok(WalletVault {
+            dir,
+            master_secret: SecretString::from(master_secret),
+            kdf,
+            wallets: RwLock::new(wallets),
+        })
+    }
+
+    pub fn create_wallet(&self) -> Result<VaultWalletInfo> {
+        let wallet = EvmWallet::new_random()?;
+        self.store(wallet)
+    }
+
+    pub fn import_private_key(&self, private_key: &str) -> Result<VaultWalletInfo> {
+        let wallet = EvmWallet::create_wallet_from_private_key(private_key)?;
+        self.store(wallet)
+    }
+
+    pub fn store(&self, wallet: EvmWallet) -> Result<VaultWalletInfo> {
+        let wallet_id = Uuid::new_v4().to_string();
+        let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let vault_entry = VaultEntry {
+            wallet_id: wallet_id.clone(),
+            address: wallet.address.clone(),
+            created_at,
+            keystore: wallet.encrypt_keystore(self.master_secret.expose_secret(), self.kdf)?,
+        };
+        write_private_file(&self.entry_path(&wallet_id), &serde_json::to_string_pretty(&vault_entry)?)?;
+
+        let info = VaultWalletInfo {
+            wallet_id: wallet_id.clone(),
+            address: wallet.address.clone(),
+            created_at,
+        };
+        self.wallets.write().unwrap().insert(wallet_id, (info.clone(), wallet));
+        Ok(info)
+    }
+
+    pub fn get_wallet(&self, wallet_id: &str) -> Result<EvmWallet> {
+        self.wallets.read().unwrap()
+            .get(wallet_id)
+            .map(|(_, wallet)| wallet.clone())
+            .ok_or_else(|| anyhow::anyhow!("Wallet not found in vault: {}", wallet_id))
+    }
+
+    pub fn list(&self) -> Vec<VaultWalletInfo> {
+        let mut wallets: Vec<VaultWalletInfo> = self.wallets.read().unwrap()
+            .values()
+            .map(|(info, _)| info.clone())
+            .collect();
+        wallets.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.wallet_id.cmp(&b.wallet_id)));
+        wallets
+    }
+
+    pub fn delete(&self, wallet_id: &str) -> Result<()> {
+        let mut wallets = self.wallets.write().unwrap();
+        if !wallets.contains_key(wallet_id) {
+            return Err(anyhow::anyhow!("Wallet not found in vault: {}", wallet_id));
+        }
+        fs::remove_file(self.entry_path(wallet_id))?;
+        wallets.remove(wallet_id);
+        Ok(())
+    }
+
+    fn entry_path(&self, wallet_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", wallet_id))
+    }
+}
+
+fn write_private_file(path: &Path, contents: &str) -> Result<()> {
+    let tmp_path = path.with_extension("json.tmp");
+    // The mode is set at creation, so the file is never readable by others, not even briefly.
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&tmp_path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+pub fn init_vault_from_env() -> Result<bool> {
+    let master_secret = match env::var("VAULT_MASTER_SECRET") {
+        Ok(secret) if !secret.is_empty() => SecretString::new(secret),
+        _ => return Ok(false),
+    };
+    // Vault wallets sign without the caller knowing the key, so the vault never runs unauthenticated.
+    let api_token = match env::var("VAULT_API_TOKEN") {
+        Ok(token) if !token.trim().is_empty() => SecretString::new(token.trim()),
+        _ => return Err(anyhow::anyhow!("VAULT_API_TOKEN must be set when VAULT_MASTER_SECRET enables the wallet vault")),
+    };
+    let dir = env::var("VAULT_DIR").unwrap_or_else(|_| "./vault".to_string());
+    let vault = WalletVault::open(dir, master_secret.expose_secret())?;
+    VAULT.set(vault).map_err(|_| anyhow::anyhow!("Wallet vault is already initialized"))?;
+    VAULT_API_TOKEN.set(api_token).map_err(|_| anyhow::anyhow!("Wallet vault is already initialized"))?;
+    Ok(true)
+}
+
+pub fn global_vault() -> Result<&'static WalletVault> {
+    VAULT.get().ok_or_else(|| anyhow::anyhow!("Wallet vault is not configured. Set VAULT_MASTER_SECRET to enable it"))
+}
+
+// Checks an `Authorization` header value against `Bearer <VAULT_API_TOKEN>`.
+pub fn vault_token_authorized(authorization: Option<&str>) -> bool {
+    match VAULT_API_TOKEN.get() {
+        Some(expected) => bearer_token_matches(expected.expose_secret(), authorization),
+        None => false,
+    }
+}
+
+pub fn bearer_token_matches(expected: &str, authorization: Option<&str>) -> bool {
+    let Some(token) = authorization.and_then(|value| value.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    // Constant-time over the token bytes so the comparison does not leak a matching prefix.
+    let (expected, token) = (expected.as_bytes(), token.trim().as_bytes());
+    !expected.is_empty()
+        && expected.len() == token.len()
+        && expected.iter().zip(token).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+}
@@ -1,65 +1,198 @@
 use alloy::{
     network::EthereumWallet,
-    primitives::{Address, U256, Bytes, TxHash, FixedBytes},
-    providers::{Provider, ProviderBuilder},
+    primitives::{eip191_hash_message, Address, U256, Bytes, TxHash, TxKind, FixedBytes, Signature},
+    providers::{PendingTransactionBuilder, Provider, ProviderBuilder},
     rpc::types::{TransactionRequest, Filter},
-    signers::local::PrivateKeySigner,
-    consensus::Transaction,
+    signers::{local::PrivateKeySigner, utils::public_key_to_address},
+    consensus::{transaction::SignerRecoverable, SignableTransaction, Transaction, TxEip1559, TxEip2930, TxEnvelope, TxLegacy, TxType},
+    eips::{Decodable2718, Encodable2718},
+    dyn_abi::TypedData,
     network::TransactionResponse,
 };
 use anyhow::Result;
 use bip39::{Language, Mnemonic};
-use k256::ecdsa::SigningKey;
+use coins_bip32::{
+    enc::{MainnetEncoder, XKeyEncoder},
+    primitives::Hint,
+    xkeys::{Parent, XPriv},
+};
+use k256::ecdsa::{SigningKey, VerifyingKey};
 use rand::thread_rng;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 use hex;
-use tracing::{debug, warn};
-use crate::types::{Erc20TransferEvent, TransactionReceipt};
+use tracing::{debug, info, warn};
+use crate::keystore::{self, Keystore, KeystoreKdf};
+use crate::secret::SecretString;
+use crate::signer::{BackendTxSigner, LocalSigner, SignerBackend};
+use zeroize::Zeroizing;
+use crate::slip39;
+use crate::types::{
+    ConfirmedTransaction, DecodedErc20Transfer, DecodedTransaction, DerivedAddress, Erc20TransferEvent, MessageVerification, MnemonicValidation, OfflineTxParams, PublicKeyInfo,
+    ReplacementError, ReplacementKind, ReplacementTransaction, SignedTransaction,
+    SweepResult, SweepTokenTransfer, TransactionReceipt, TransactionType, TxOptions, UnknownMnemonicWord,
+};
+
+pub const MAX_DERIVATION_COUNT: u32 = 1000;
+pub(crate) const BIP32_HARDENED_OFFSET: u32 = 0x8000_0000;
+const SWEEP_RECEIPT_TIMEOUT: Duration = Duration::from_secs(180);
+pub const MAX_CONFIRMATIONS: u64 = 64;
+pub const MAX_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(600);
+pub const MAX_MNEMONIC_WORDS: usize = 24;
+pub(crate) const ERC20_TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvmWallet {
-    pub private_key: String,
+    pub private_key: SecretString,
     pub public_key: String,
     pub address: String,
-    pub mnemonic: Option<String>,
+    pub mnemonic: Option<SecretString>,
+    pub derivation_path: Option<String>,
     #[serde(skip)]
-    pub signer: Option<PrivateKeySigner>,
+    pub signer: Option<Arc<dyn SignerBackend>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WalletResponse {
     pub address: String,
-    pub private_key: String,
+    pub private_key: SecretString,
     pub public_key: String,
-    pub mnemonic: Option<String>,
+    pub mnemonic: Option<SecretString>,
+    pub derivation_path: Option<String>,
+}
+
+// Only wallets holding local key material can be returned as a WalletResponse; keystore and remote
+// signer backends have nothing to export.
+impl TryFrom<EvmWallet> for WalletResponse {
+    type Error = anyhow::Error;
+
+    fn try_from(wallet: EvmWallet) -> Result<Self> {
+        wallet.private_key_hex()?;
+        wallet.public_key_hex()?;
+        Ok(WalletResponse {
+            address: wallet.address,
+            private_key: wallet.private_key,
+            public_key: wallet.public_key,
+            mnemonic: wallet.mnemonic,
+            derivation_path: wallet.derivation_path,
+        })
+    }
+}
+
+// Network fee suggestions with the caller's overrides applied on top.
+#[derive(Debug, Clone, Copy)]
+struct FeeSettings {
+    tx_type: TransactionType,
+    max_fee_per_gas: U256,
+    max_priority_fee_per_gas: U256,
+    gas_price: U256,
+}
+
+impl FeeSettings {
+    async fn resolve(options: &TxOptions, rpc_url: &str) -> Self {
+        let tx_type = crate::utils::resolve_transaction_type(options.requested_transaction_type(), rpc_url).await;
+        let (network_max_fee, network_priority_fee, network_gas_price) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+
+        // Only one of the two EIP-1559 fees may be given; the suggested other one is moved so that
+        // priority <= max fee still holds without overriding what the caller asked for.
+        let priority_override = options.max_priority_fee_per_gas.map(U256::from);
+        let max_fee_per_gas = match options.max_fee_per_gas {
+            Some(max_fee_per_gas) => U256::from(max_fee_per_gas),
+            None => network_max_fee.max(priority_override.unwrap_or_default()),
+        };
+        let max_priority_fee_per_gas = priority_override.unwrap_or_else(|| network_priority_fee.min(max_fee_per_gas));
+
+        FeeSettings {
+            tx_type,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            gas_price: options.gas_price.map(U256::from).unwrap_or(network_gas_price),
+        }
+    }
+
+    fn apply(&self, tx: TransactionRequest) -> Result<TransactionRequest> {
+        crate::utils::apply_fee_fields(tx, self.tx_type, self.max_fee_per_gas, self.max_priority_fee_per_gas, self.gas_price)
+    }
+
+    fn effective_gas_price(&self) -> U256 {
+        crate::utils::effective_gas_price(self.tx_type, self.gas_price, self.max_priority_fee_per_gas).min(self.max_price())
+    }
+
+    // The most the transaction can pay per gas, used for the balance check.
+    fn max_price(&self) -> U256 {
+        match self.tx_type {
+            TransactionType::Eip1559 => self.max_fee_per_gas,
+            TransactionType::Legacy | TransactionType::Eip2930 => self.gas_price,
+        }
+    }
 }
 
 impl EvmWallet {
 
     pub fn new_random() -> Result<Self> {
-        let signing_key = SigningKey::random(&mut thread_rng());
-        let private_key_bytes = signing_key.to_bytes();
-        let private_key = hex::encode(private_key_bytes);
-        
-        let signer = PrivateKeySigner::from_str(&private_key)?;
-        let address = signer.address();
-        
-        let public_key = hex::encode(signing_key.verifying_key().to_encoded_point(false).as_bytes());
-        
-        Ok(EvmWallet {
-            private_key,
-            public_key,
-            address: format!("{:#x}", address),
-            mnemonic: None,
-            signer: Some(signer),
-        })
+        Self::from_signing_key(&Self::random_signing_key(), None, None)
+    }
+
+    pub(crate) fn random_signing_key() -> SigningKey {
+        SigningKey::random(&mut thread_rng())
     }
 
     pub fn address_from_private_key(private_key: &str) -> Result<String> {
         let signer = PrivateKeySigner::from_str(private_key)?;
         let address = signer.address();
-        Ok(format!("{:#x}", address))
+        Ok(address.to_checksum(None))
+    }
+
+    // `from_signer` wallets leave the key fields empty; these accessors turn that into a clear error.
+    pub fn private_key_hex(&self) -> Result<&str> {
+        match self.private_key.expose_secret() {
+            "" => Err(anyhow::anyhow!("Private key is not available for this signer backend")),
+            private_key => Ok(private_key),
+        }
+    }
+
+    pub fn public_key_hex(&self) -> Result<&str> {
+        match self.public_key.as_str() {
+            "" => Err(anyhow::anyhow!("Public key is not available for this signer backend")),
+            public_key => Ok(public_key),
+        }
+    }
+
+    pub fn compressed_public_key(&self) -> Result<String> {
+        let verifying_key = Self::parse_public_key(self.public_key_hex()?)?;
+        Ok(hex::encode(verifying_key.to_encoded_point(true).as_bytes()))
+    }
+
+    pub fn public_key_info(public_key: &str) -> Result<PublicKeyInfo> {
+        let verifying_key = Self::parse_public_key(public_key)?;
+        Ok(PublicKeyInfo {
+            address: public_key_to_address(&verifying_key).to_checksum(None),
+            public_key: hex::encode(verifying_key.to_encoded_point(false).as_bytes()),
+            compressed_public_key: hex::encode(verifying_key.to_encoded_point(true).as_bytes()),
+        })
+    }
+
+    pub fn address_from_public_key(public_key: &str) -> Result<String> {
+        let verifying_key = Self::parse_public_key(public_key)?;
+        Ok(public_key_to_address(&verifying_key).to_checksum(None))
+    }
+
+    // Accepts SEC1 compressed (33 bytes) or uncompressed (65 bytes) keys, and the bare 64-byte X||Y form.
+    pub fn parse_public_key(public_key: &str) -> Result<VerifyingKey> {
+        let mut bytes = hex::decode(public_key.trim().trim_start_matches("0x"))
+            .map_err(|e| anyhow::anyhow!("Invalid public key hex: {}", e))?;
+        if bytes.len() == 64 {
+            bytes.insert(0, 0x04);
+        }
+        match (bytes.len(), bytes.first().copied().unwrap_or_default()) {
+            (33, 0x02 | 0x03) | (65, 0x04) => {}
+            (33 | 65, prefix) => return Err(anyhow::anyhow!("Invalid public key prefix: 0x{:02x}", prefix)),
+            (len, _) => return Err(anyhow::anyhow!("Invalid public key length: {} bytes (expected 33 or 65)", len)),
+        }
+        VerifyingKey::from_sec1_bytes(&bytes)
+            .map_err(|_| anyhow::anyhow!("Invalid public key: not a valid secp256k1 point"))
     }
 
     pub fn create_wallet_from_private_key(private_key: &str) -> Result<Self> {
@@ -82,19 +215,43 @@ impl EvmWallet {
         let public_key = hex::encode(signing_key.verifying_key().to_encoded_point(false).as_bytes());
         
         Ok(EvmWallet {
-            private_key: private_key.to_string(),
+            private_key: SecretString::from(private_key),
             public_key,
-            address: format!("{:#x}", address),
+            address: address.to_checksum(None),
             mnemonic: None,
-            signer: Some(signer),
+            derivation_path: None,
+            signer: Some(Arc::new(LocalSigner::new(signer))),
         })
     }
 
-    pub fn generate_mnemonic() -> Result<String> {
+    pub fn encrypt_keystore(&self, password: &str, kdf: KeystoreKdf) -> Result<Keystore> {
+        let private_key_bytes = Zeroizing::new(hex::decode(self.private_key_hex()?.trim_start_matches("0x"))
+            .map_err(|e| anyhow::anyhow!("Failed to decode private key hex: {}", e))?);
+        keystore::encrypt_key(&private_key_bytes, password, kdf)
+    }
+
+    pub fn from_keystore(keystore: &Keystore, password: &str) -> Result<Self> {
+        let private_key_bytes = keystore::decrypt_key(keystore, password)?;
+        let signing_key = SigningKey::from_slice(&private_key_bytes)
+            .map_err(|e| anyhow::anyhow!("Failed to create signing key: {}", e))?;
+        Self::from_signing_key(&signing_key, None, None)
+    }
+
+    pub fn from_keystore_json(keystore_json: &str, password: &str) -> Result<Self> {
+        let keystore: Keystore = serde_json::from_str(keystore_json)
+            .map_err(|e| anyhow::anyhow!("Invalid keystore JSON: {}", e))?;
+        Self::from_keystore(&keystore, password)
+    }
+
+    pub fn generate_mnemonic() -> Result<SecretString> {
         Self::generate_mnemonic_with_words(24)
     }
 
-    pub fn generate_mnemonic_with_words(word_count: usize) -> Result<String> {
+    pub fn generate_mnemonic_with_words(word_count: usize) -> Result<SecretString> {
+        Self::generate_mnemonic_in(Language::English, word_count)
+    }
+
+    pub fn generate_mnemonic_in(language: Language, word_count: usize) -> Result<SecretString> {
         let entropy_bits = match word_count {
             12 => 128,
             15 => 160,
@@ -105,29 +262,306 @@ impl EvmWallet {
         };
         
         let entropy_bytes = entropy_bits / 8;
-        let mut entropy = vec![0u8; entropy_bytes];
+        let mut entropy = Zeroizing::new(vec![0u8; entropy_bytes]);
         getrandom::getrandom(&mut entropy)?;
         
-        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)?;
-        Ok(mnemonic.to_string())
+        let mnemonic = Mnemonic::from_entropy_in(language, &entropy)?;
+        Ok(SecretString::new(mnemonic.to_string()))
+    }
+
+    pub fn derivation_path(account: u32, index: u32) -> String {
+        format!("m/44'/60'/{}'/0/{}", account, index)
     }
 
     pub fn from_mnemonic(mnemonic_phrase: &str) -> Result<Self> {
-        let mnemonic = Mnemonic::parse_in(Language::English, mnemonic_phrase)?;
-        let seed = mnemonic.to_seed("");
+        Self::from_mnemonic_with_index(mnemonic_phrase, 0, 0)
+    }
+
+    pub fn from_mnemonic_with_index(mnemonic_phrase: &str, account: u32, index: u32) -> Result<Self> {
+        Self::from_mnemonic_with_passphrase(mnemonic_phrase, "", account, index)
+    }
+
+    pub fn from_mnemonic_with_passphrase(
+        mnemonic_phrase: &str,
+        passphrase: &str,
+        account: u32,
+        index: u32,
+    ) -> Result<Self> {
+        Self::from_mnemonic_in(None, mnemonic_phrase, passphrase, account, index)
+    }
+
+    pub fn from_mnemonic_in(
+        language: Option<Language>,
+        mnemonic_phrase: &str,
+        passphrase: &str,
+        account: u32,
+        index: u32,
+    ) -> Result<Self> {
+        // An index past the hardened offset would derive a hardened child under a non-hardened path.
+        derivation_range(index, 1)?;
+        let path = Self::derivation_path(account, index);
+        let mnemonic = Self::parse_mnemonic(mnemonic_phrase, language)?;
+        let account_key = Self::account_xpriv_from_mnemonic(&mnemonic, passphrase, account)?;
+        let child = account_key.derive_path(format!("m/0/{}", index).as_str())
+            .map_err(|e| anyhow::anyhow!("Failed to derive key at {}: {}", path, e))?;
+        let signing_key: &SigningKey = child.as_ref();
+
+        Self::from_signing_key(signing_key, Some(SecretString::from(mnemonic_phrase)), Some(path))
+    }
+
+    pub fn account_xpub(mnemonic_phrase: &str, passphrase: &str, account: u32) -> Result<String> {
+        let account_key = Self::account_xpriv(mnemonic_phrase, passphrase, account)?;
+        let xpub = MainnetEncoder::xpub_to_base58(&account_key.verify_key())
+            .map_err(|e| anyhow::anyhow!("Failed to encode xpub: {}", e))?;
+        Ok(xpub)
+    }
+
+    pub fn derive_addresses(
+        mnemonic_phrase: &str,
+        passphrase: &str,
+        account: u32,
+        start_index: u32,
+        count: u32,
+    ) -> Result<Vec<DerivedAddress>> {
+        let indices = derivation_range(start_index, count)?;
+
+        let account_key = Self::account_xpriv(mnemonic_phrase, passphrase, account)?;
+        let external_chain = account_key.derive_child(0)
+            .map_err(|e| anyhow::anyhow!("Failed to derive external chain: {}", e))?;
+
+        let mut addresses = Vec::with_capacity(count as usize);
+        for index in indices {
+            let child = external_chain.derive_child(index)
+                .map_err(|e| anyhow::anyhow!("Failed to derive address index {}: {}", index, e))?;
+            let signing_key: &SigningKey = child.as_ref();
+            let address = public_key_to_address(signing_key.verifying_key());
+            addresses.push(DerivedAddress {
+                index,
+                derivation_path: Self::derivation_path(account, index),
+                address: address.to_checksum(None),
+            });
+        }
+        Ok(addresses)
+    }
+
+    pub fn parse_mnemonic(mnemonic_phrase: &str, language: Option<Language>) -> Result<Mnemonic> {
+        if let Some(language) = language {
+            return Mnemonic::parse_in(language, mnemonic_phrase)
+                .map_err(|e| anyhow::anyhow!("Invalid {} mnemonic: {}", language, e));
+        }
+        match Mnemonic::parse(mnemonic_phrase) {
+            Ok(mnemonic) => Ok(mnemonic),
+            // Some wordlists (e.g. simplified and traditional Chinese) share words; any candidate
+            // whose checksum verifies yields the same seed because the seed is computed from the words.
+            Err(bip39::Error::AmbiguousLanguages(candidates)) => candidates
+                .iter()
+                .find_map(|language| Mnemonic::parse_in(language, mnemonic_phrase).ok())
+                .ok_or_else(|| anyhow::anyhow!("Invalid mnemonic: checksum does not match in any candidate language")),
+            Err(e) => Err(anyhow::anyhow!("Invalid mnemonic: {}", e)),
+        }
+    }
+
+    pub fn validate_mnemonic(mnemonic_phrase: &str, language: Option<Language>) -> MnemonicValidation {
+        let mut normalized = std::borrow::Cow::Borrowed(mnemonic_phrase);
+        Mnemonic::normalize_utf8_cow(&mut normalized);
+        let normalized = normalized.to_lowercase();
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        let word_count = words.len();
+        let word_count_valid = matches!(word_count, 12 | 15 | 18 | 21 | 24);
+
+        let language = language.unwrap_or_else(|| {
+            Language::all()
+                .iter()
+                .copied()
+                .max_by_key(|language| {
+                    let known = words.iter().filter(|word| language.find_word(word).is_some()).count();
+                    (known, *language == Language::English)
+                })
+                .unwrap_or(Language::English)
+        });
+
+        let unknown_words: Vec<UnknownMnemonicWord> = words
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| language.find_word(word).is_none())
+            .map(|(position, word)| UnknownMnemonicWord {
+                position: position + 1,
+                word: word.to_string(),
+                // Suggestions cost a wordlist scan per word; past the longest valid phrase they only help an attacker.
+                suggestions: if word_count <= MAX_MNEMONIC_WORDS {
+                    suggest_mnemonic_words(language, word)
+                } else {
+                    Vec::new()
+                },
+            })
+            .collect();
+
+        let checksum_valid = if word_count_valid && unknown_words.is_empty() {
+            Some(Mnemonic::parse_in_normalized(language, &words.join(" ")).is_ok())
+        } else {
+            None
+        };
+
+        MnemonicValidation {
+            valid: checksum_valid == Some(true),
+            word_count,
+            word_count_valid,
+            language: crate::utils::mnemonic_language_name(language).to_string(),
+            unknown_words,
+            checksum_valid,
+        }
+    }
+
+    fn account_xpriv(mnemonic_phrase: &str, passphrase: &str, account: u32) -> Result<XPriv> {
+        let mnemonic = Self::parse_mnemonic(mnemonic_phrase, None)?;
+        Self::account_xpriv_from_mnemonic(&mnemonic, passphrase, account)
+    }
+
+    fn account_xpriv_from_mnemonic(mnemonic: &Mnemonic, passphrase: &str, account: u32) -> Result<XPriv> {
+        if account >= BIP32_HARDENED_OFFSET {
+            return Err(anyhow::anyhow!("Account {} exceeds the hardened derivation limit ({})", account, BIP32_HARDENED_OFFSET - 1));
+        }
+        let seed = mnemonic.to_seed(passphrase);
+        let root = XPriv::root_from_seed(&seed, Some(Hint::Legacy))
+            .map_err(|e| anyhow::anyhow!("Failed to create root key from seed: {}", e))?;
+        let account_path = format!("m/44'/60'/{}'", account);
+        root.derive_path(account_path.as_str())
+            .map_err(|e| anyhow::anyhow!("Failed to derive key at {}: {}", account_path, e))
+    }
+
+    // Migration mode for wallets created before BIP-44 derivation was introduced:
+    // the private key is the first 32 bytes of the BIP-39 seed, which no other wallet software reproduces.
+    pub fn from_mnemonic_legacy(mnemonic_phrase: &str, passphrase: &str) -> Result<Self> {
+        Self::from_mnemonic_legacy_in(None, mnemonic_phrase, passphrase)
+    }
+
+    pub fn from_mnemonic_legacy_in(language: Option<Language>, mnemonic_phrase: &str, passphrase: &str) -> Result<Self> {
+        let mnemonic = Self::parse_mnemonic(mnemonic_phrase, language)?;
+        let seed = mnemonic.to_seed(passphrase);
         let signing_key = SigningKey::from_slice(&seed[0..32])?;
-        let private_key = hex::encode(signing_key.to_bytes());
-        let signer = PrivateKeySigner::from_str(&private_key)?;
+
+        Self::from_signing_key(&signing_key, Some(SecretString::from(mnemonic_phrase)), None)
+    }
+
+    pub fn split_mnemonic_slip39(
+        mnemonic_phrase: &str,
+        language: Option<Language>,
+        passphrase: &str,
+        threshold: u8,
+        share_count: u8,
+    ) -> Result<Vec<SecretString>> {
+        let mnemonic = Self::parse_mnemonic(mnemonic_phrase, language)?;
+        let mut groups = slip39::generate_slip39_shares(
+            &Zeroizing::new(mnemonic.to_entropy()),
+            passphrase,
+            1,
+            &[(threshold, share_count)],
+            slip39::SLIP39_DEFAULT_ITERATION_EXPONENT,
+        )?;
+        Ok(groups.remove(0))
+    }
+
+    // Shares carry only the BIP-39 entropy, so the wordlist language is needed to rebuild the same phrase and seed.
+    pub fn from_slip39_shares(
+        shares: &[SecretString],
+        passphrase: &str,
+        language: Language,
+        max_iteration_exponent: u8,
+    ) -> Result<Self> {
+        let entropy = Zeroizing::new(slip39::combine_slip39_shares_with_limit(shares, passphrase, max_iteration_exponent)?);
+        let mnemonic = Mnemonic::from_entropy_in(language, &entropy)
+            .map_err(|e| anyhow::anyhow!("Recovered secret is not valid BIP-39 entropy: {}", e))?;
+        let phrase = SecretString::new(mnemonic.to_string());
+        Self::from_mnemonic_in(Some(language), phrase.expose_secret(), "", 0, 0)
+    }
+
+    pub(crate) fn from_signing_key(
+        signing_key: &SigningKey,
+        mnemonic: Option<SecretString>,
+        derivation_path: Option<String>,
+    ) -> Result<Self> {
+        let private_key = SecretString::new(hex::encode(signing_key.to_bytes()));
+        let signer = PrivateKeySigner::from_str(private_key.expose_secret())?;
         let address = signer.address();
-        
+
         let public_key = hex::encode(signing_key.verifying_key().to_encoded_point(false).as_bytes());
-        
+
         Ok(EvmWallet {
             private_key,
             public_key,
-            address: format!("{:#x}", address),
-            mnemonic: Some(mnemonic_phrase.to_string()),
+            address: address.to_checksum(None),
+            mnemonic,
+            derivation_path,
+            signer: Some(Arc::new(LocalSigner::new(signer))),
+        })
+    }
+
+    // Wallets backed by a keystore or remote signer carry no private key material.
+    pub fn from_signer(signer: Arc<dyn SignerBackend>) -> Self {
+        EvmWallet {
+            private_key: SecretString::default(),
+            public_key: String::new(),
+            address: signer.address().to_checksum(None),
+            mnemonic: None,
+            derivation_path: None,
             signer: Some(signer),
+        }
+    }
+
+    pub fn signer(&self) -> Result<&Arc<dyn SignerBackend>> {
+        self.signer.as_ref().ok_or_else(|| anyhow::anyhow!("Wallet has no signer"))
+    }
+
+    fn ethereum_wallet(&self) -> Result<EthereumWallet> {
+        Ok(EthereumWallet::new(BackendTxSigner(self.signer()?.clone())))
+    }
+
+    pub async fn sign_message(&self, message: &[u8]) -> Result<String> {
+        let signature = self.signer()?.sign_hash(&eip191_hash_message(message)).await?;
+        Ok(format!("0x{}", hex::encode(signature.as_bytes())))
+    }
+
+    pub fn recover_message_signer(message: &[u8], signature: &str) -> Result<Address> {
+        let signature = Signature::from_str(signature.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid signature: {}", e))?;
+        signature.recover_address_from_msg(message)
+            .map_err(|e| anyhow::anyhow!("Failed to recover signer: {}", e))
+    }
+
+    pub fn verify_message(message: &[u8], signature: &str, expected_address: &str) -> Result<MessageVerification> {
+        let expected = crate::utils::parse_address(expected_address)?;
+        let recovered = Self::recover_message_signer(message, signature)?;
+        Ok(MessageVerification {
+            recovered_address: recovered.to_checksum(None),
+            expected_address: expected.to_checksum(None),
+            is_valid: recovered == expected,
+        })
+    }
+
+    pub async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<String> {
+        let signer = self.signer()?;
+        let hash = typed_data.eip712_signing_hash()
+            .map_err(|e| anyhow::anyhow!("Invalid typed data: {}", e))?;
+        let signature = signer.sign_hash(&hash).await?;
+        Ok(format!("0x{}", hex::encode(signature.as_bytes())))
+    }
+
+    pub fn recover_typed_data_signer(typed_data: &TypedData, signature: &str) -> Result<Address> {
+        let hash = typed_data.eip712_signing_hash()
+            .map_err(|e| anyhow::anyhow!("Invalid typed data: {}", e))?;
+        let signature = Signature::from_str(signature.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid signature: {}", e))?;
+        signature.recover_address_from_prehash(&hash)
+            .map_err(|e| anyhow::anyhow!("Failed to recover signer: {}", e))
+    }
+
+    pub fn verify_typed_data(typed_data: &TypedData, signature: &str, expected_address: &str) -> Result<MessageVerification> {
+        let expected = crate::utils::parse_address(expected_address)?;
+        let recovered = Self::recover_typed_data_signer(typed_data, signature)?;
+        Ok(MessageVerification {
+            recovered_address: recovered.to_checksum(None),
+            expected_address: expected.to_checksum(None),
+            is_valid: recovered == expected,
         })
     }
 
@@ -137,29 +571,42 @@ impl EvmWallet {
         amount_wei: U256,
         rpc_url: &str,
     ) -> Result<TxHash> {
+        self.send_native_coin_with_options(to, amount_wei, rpc_url, &TxOptions::default()).await
+    }
+
+    pub async fn send_native_coin_with_options(
+        &self,
+        to: &str,
+        amount_wei: U256,
+        rpc_url: &str,
+        options: &TxOptions,
+    ) -> Result<TxHash> {
+        crate::utils::validate_tx_options(options, &crate::utils::configured_fee_caps())?;
         let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(self.signer.clone().unwrap()))
+            .wallet(self.ethereum_wallet()?)
             .connect_http(rpc_url.parse()?);
 
-        let to_address = Address::from_str(to)?;
+        let to_address = crate::utils::parse_address(to)?;
 
-        let estimate_tx = TransactionRequest::default()
-            .to(to_address)
-            .value(amount_wei);
-        let gas_limit = provider.estimate_gas(estimate_tx).await?;
+        let gas_limit = match options.gas_limit {
+            Some(gas_limit) => gas_limit,
+            None => {
+                let estimate_tx = TransactionRequest::default()
+                    .to(to_address)
+                    .value(amount_wei);
+                provider.estimate_gas(estimate_tx).await?
+            }
+        };
         
-        let (max_fee_per_gas, max_priority_fee_per_gas, _) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+        let fees = FeeSettings::resolve(options, rpc_url).await;
 
         let tx = TransactionRequest::default()
             .to(to_address)
             .value(amount_wei)
-            .gas_limit(gas_limit)
-            .max_fee_per_gas(max_fee_per_gas.to::<u128>())
-            .max_priority_fee_per_gas(max_priority_fee_per_gas.to::<u128>());
+            .gas_limit(gas_limit);
+        let tx = fees.apply(tx)?;
 
-        let pending_tx = provider.send_transaction(tx).await?;
-        let tx_hash = *pending_tx.tx_hash();
-        Ok(tx_hash)
+        self.send_with_managed_nonce(&provider, tx, rpc_url, options.nonce).await
     }
 
     pub async fn send_erc20_token(
@@ -169,42 +616,544 @@ impl EvmWallet {
         token_address: &str,
         rpc_url: &str,
     ) -> Result<TxHash> {
+        self.send_erc20_token_with_options(to, amount_token_wei, token_address, rpc_url, &TxOptions::default()).await
+    }
+
+    pub async fn send_erc20_token_with_options(
+        &self,
+        to: &str,
+        amount_token_wei: U256,
+        token_address: &str,
+        rpc_url: &str,
+        options: &TxOptions,
+    ) -> Result<TxHash> {
+        crate::utils::validate_tx_options(options, &crate::utils::configured_fee_caps())?;
         let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(self.signer.clone().unwrap()))
+            .wallet(self.ethereum_wallet()?)
             .connect_http(rpc_url.parse()?);
 
-        let token_addr = Address::from_str(token_address)?;
-        let to_address = Address::from_str(to)?;
+        let token_addr = crate::utils::parse_address(token_address)?;
+        let to_address = crate::utils::parse_address(to)?;
 
-        let function_selector = "a9059cbb";
-        let to_padded = format!("{:0>64}", format!("{:x}", to_address));
-        let amount_padded = format!("{:0>64x}", amount_token_wei);
-        
-        let data = format!("{}{}{}", function_selector, to_padded, amount_padded);
-        let call_data = Bytes::from(hex::decode(data)?);
+        let call_data = Self::erc20_transfer_calldata(to_address, amount_token_wei);
 
-        let estimate_tx = TransactionRequest::default()
-            .to(token_addr)
-            .input(call_data.clone().into());
-        let gas_limit = provider.estimate_gas(estimate_tx).await?;
+        let gas_limit = match options.gas_limit {
+            Some(gas_limit) => gas_limit,
+            None => {
+                let estimate_tx = TransactionRequest::default()
+                    .to(token_addr)
+                    .input(call_data.clone().into());
+                provider.estimate_gas(estimate_tx).await?
+            }
+        };
         
-        let (max_fee_per_gas, max_priority_fee_per_gas, _) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+        let fees = FeeSettings::resolve(options, rpc_url).await;
 
         let tx = TransactionRequest::default()
             .to(token_addr)
             .input(call_data.into())
-            .gas_limit(gas_limit)
-            .max_fee_per_gas(max_fee_per_gas.to::<u128>())
-            .max_priority_fee_per_gas(max_priority_fee_per_gas.to::<u128>());
+            .gas_limit(gas_limit);
+        let tx = fees.apply(tx)?;
+
+        self.send_with_managed_nonce(&provider, tx, rpc_url, options.nonce).await
+    }
+
+    // Every send path goes through here so concurrent requests for one wallet get distinct nonces.
+    // A caller-chosen nonce is sent as is and never retried under a different one.
+    async fn send_with_managed_nonce<P: Provider>(
+        &self,
+        provider: &P,
+        tx: TransactionRequest,
+        rpc_url: &str,
+        nonce_override: Option<u64>,
+    ) -> Result<TxHash> {
+        let from = self.signer()?.address();
+        let mut lease = crate::nonce::global_nonce_manager().lease(rpc_url, from).await;
+        let pending_count = match provider.get_transaction_count(from).pending().await {
+            Ok(count) => count,
+            Err(e) => {
+                lease.invalidate();
+                return Err(e.into());
+            }
+        };
+        let allocated = lease.allocate(pending_count);
+        let mut nonce = nonce_override.unwrap_or(allocated);
+        let mut result = provider.send_transaction(tx.clone().from(from).nonce(nonce)).await;
+
+        if let Err(e) = &result {
+            if nonce_override.is_none() && crate::nonce::is_nonce_conflict(&e.to_string()) {
+                let pending_count = match provider.get_transaction_count(from).pending().await {
+                    Ok(count) => count,
+                    Err(e) => {
+                        lease.invalidate();
+                        return Err(e.into());
+                    }
+                };
+                let retry_nonce = lease.resync(pending_count, nonce);
+                warn!("Nonce {} for {} was rejected ({}); retrying with {}", nonce, from, e, retry_nonce);
+                nonce = retry_nonce;
+                result = provider.send_transaction(tx.from(from).nonce(nonce)).await;
+            }
+        }
+
+        match result {
+            Ok(pending_tx) => {
+                // Only an explicit nonce equal to the allocated one advances tracking: a lower one (e.g. a
+                // manual replacement) would rewind it, and a higher one leaves a gap the node may never fill.
+                if nonce_override.is_none_or(|n| n == allocated) {
+                    lease.commit(nonce);
+                }
+                Ok(*pending_tx.tx_hash())
+            }
+            Err(e) => {
+                lease.invalidate();
+                Err(e.into())
+            }
+        }
+    }
+
+    // Re-sends the nonce of a pending transaction with higher fees. A speed-up repeats the original
+    // call; a cancel replaces it with a zero-value transfer to self. The nonce stays fixed, so this
+    // path does not go through the nonce manager.
+    pub async fn replace_transaction(
+        &self,
+        tx_hash: &str,
+        kind: ReplacementKind,
+        rpc_url: &str,
+    ) -> Result<ReplacementTransaction, ReplacementError> {
+        let from_address = self.signer()?.address();
+        let original_hash = TxHash::from_str(tx_hash.trim())
+            .map_err(|e| ReplacementError::InvalidHash(e.to_string()))?;
+        let provider = ProviderBuilder::new()
+            .wallet(self.ethereum_wallet()?)
+            .connect_http(rpc_url.parse().map_err(|e| anyhow::anyhow!("Invalid RPC URL {}: {}", rpc_url, e))?);
+
+        let original = provider.get_transaction_by_hash(original_hash).await?
+            .ok_or_else(|| ReplacementError::NotFound(format!("{:#x}", original_hash)))?;
+        if let Some(block_number) = original.block_number {
+            return Err(ReplacementError::NotReplaceable(format!(
+                "Transaction {:#x} is already mined in block {} and cannot be replaced",
+                original_hash, block_number
+            )));
+        }
+        if original.from() != from_address {
+            return Err(ReplacementError::NotReplaceable(format!(
+                "Transaction {:#x} was sent by {}, not by {}, and cannot be replaced",
+                original_hash,
+                original.from().to_checksum(None),
+                from_address.to_checksum(None)
+            )));
+        }
+        let nonce = original.nonce();
+        if provider.get_transaction_count(from_address).await? > nonce {
+            return Err(ReplacementError::NotReplaceable(format!(
+                "Nonce {} of transaction {:#x} is already used by a mined transaction and cannot be replaced",
+                nonce, original_hash
+            )));
+        }
+        let tx_type = match original.inner.tx_type() {
+            TxType::Legacy => TransactionType::Legacy,
+            TxType::Eip2930 => TransactionType::Eip2930,
+            TxType::Eip1559 => TransactionType::Eip1559,
+            other => return Err(ReplacementError::NotReplaceable(format!(
+                "{} transaction {:#x} cannot be replaced: only legacy, EIP-2930 and EIP-1559 are supported",
+                other, original_hash
+            ))),
+        };
+
+        // The bumped fees satisfy the replacement rule; the current network fees keep the replacement
+        // from being stuck again when the original was underpriced to begin with.
+        let (network_max_fee, network_priority_fee, network_gas_price) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+        let gas_price = crate::utils::bump_replacement_fee(Transaction::gas_price(&original).unwrap_or_default())
+            .max(network_gas_price.saturating_to::<u128>());
+        let max_priority_fee_per_gas = crate::utils::bump_replacement_fee(original.max_priority_fee_per_gas().unwrap_or_default())
+            .max(network_priority_fee.saturating_to::<u128>());
+        let max_fee_per_gas = crate::utils::bump_replacement_fee(Transaction::max_fee_per_gas(&original))
+            .max(network_max_fee.saturating_to::<u128>())
+            .max(max_priority_fee_per_gas);
+
+        let mut tx = match kind {
+            ReplacementKind::SpeedUp => {
+                let mut tx = TransactionRequest::default()
+                    .value(original.value())
+                    .input(original.input().clone().into())
+                    .gas_limit(original.gas_limit());
+                tx.to = Some(original.kind());
+                tx
+            }
+            ReplacementKind::Cancel => TransactionRequest::default()
+                .to(from_address)
+                .value(U256::ZERO)
+                .gas_limit(21_000),
+        };
+        tx = tx.from(from_address).nonce(nonce);
+        tx = crate::utils::apply_fee_fields(tx, tx_type, U256::from(max_fee_per_gas), U256::from(max_priority_fee_per_gas), U256::from(gas_price))?;
+        if kind == ReplacementKind::SpeedUp {
+            if let Some(access_list) = original.access_list() {
+                tx.access_list = Some(access_list.clone());
+            }
+        }
 
         let pending_tx = provider.send_transaction(tx).await?;
-        let tx_hash = *pending_tx.tx_hash();
-        Ok(tx_hash)
+        let (gas_price, max_fee_per_gas, max_priority_fee_per_gas) = match tx_type {
+            TransactionType::Legacy | TransactionType::Eip2930 => (Some(gas_price.to_string()), None, None),
+            TransactionType::Eip1559 => (None, Some(max_fee_per_gas.to_string()), Some(max_priority_fee_per_gas.to_string())),
+        };
+        Ok(ReplacementTransaction {
+            action: kind.as_str().to_string(),
+            original_hash: format!("{:#x}", original_hash),
+            hash: format!("{:#x}", pending_tx.tx_hash()),
+            from: from_address.to_checksum(None),
+            nonce,
+            tx_type: tx_type.as_str().to_string(),
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        })
+    }
+
+    // Returns `None` if `confirmations` blocks were not reached within `timeout`. Dropping the future
+    // (e.g. when the HTTP client disconnects) stops polling; the transaction itself stays submitted.
+    pub async fn wait_for_confirmations(
+        tx_hash: TxHash,
+        confirmations: u64,
+        timeout: Duration,
+        rpc_url: &str,
+    ) -> Result<Option<ConfirmedTransaction>> {
+        let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
+        let poll_interval = provider.client().poll_interval();
+
+        let poll = async {
+            loop {
+                match Self::confirmation_depth(&provider, tx_hash).await {
+                    // A receipt can vanish again after a reorg, so depth is re-checked on every poll.
+                    Ok(Some((receipt, depth))) if depth >= confirmations => {
+                        let transaction_fee = U256::from(receipt.effective_gas_price) * U256::from(receipt.gas_used);
+                        return ConfirmedTransaction {
+                            block_number: receipt.block_number.unwrap_or_default(),
+                            confirmations: depth,
+                            gas_used: receipt.gas_used,
+                            effective_gas_price: receipt.effective_gas_price,
+                            transaction_fee,
+                            success: receipt.status(),
+                        };
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!("Failed to poll receipt for {:#x}: {}", tx_hash, e),
+                }
+                tokio::time::sleep(poll_interval).await;
+            }
+        };
+
+        Ok(tokio::time::timeout(timeout, poll).await.ok())
+    }
+
+    async fn confirmation_depth<P: Provider>(
+        provider: &P,
+        tx_hash: TxHash,
+    ) -> Result<Option<(alloy::rpc::types::TransactionReceipt, u64)>> {
+        let Some(receipt) = provider.get_transaction_receipt(tx_hash).await? else {
+            return Ok(None);
+        };
+        let Some(block_number) = receipt.block_number else {
+            return Ok(None);
+        };
+        let head = provider.get_block_number().await?;
+        let depth = head.saturating_sub(block_number) + 1;
+        Ok(Some((receipt, depth)))
+    }
+
+    pub(crate) fn erc20_transfer_calldata(to: Address, amount: U256) -> Bytes {
+        let mut data = Vec::with_capacity(68);
+        data.extend_from_slice(&ERC20_TRANSFER_SELECTOR);
+        data.extend_from_slice(to.into_word().as_slice());
+        data.extend_from_slice(&amount.to_be_bytes::<32>());
+        Bytes::from(data)
+    }
+
+    pub async fn sign_native_transaction(&self, to: &str, amount_wei: U256, params: &OfflineTxParams) -> Result<SignedTransaction> {
+        let to_address = crate::utils::parse_address(to)?;
+        self.sign_offline_transaction(to_address, amount_wei, Bytes::new(), params).await
+    }
+
+    pub async fn sign_erc20_transaction(
+        &self,
+        to: &str,
+        amount_token_wei: U256,
+        token_address: &str,
+        params: &OfflineTxParams,
+    ) -> Result<SignedTransaction> {
+        let token_addr = crate::utils::parse_address(token_address)?;
+        let to_address = crate::utils::parse_address(to)?;
+        let call_data = Self::erc20_transfer_calldata(to_address, amount_token_wei);
+        self.sign_offline_transaction(token_addr, U256::ZERO, call_data, params).await
+    }
+
+    // No RPC access: every field that would normally be filled from the node must be supplied.
+    async fn sign_offline_transaction(&self, to: Address, value: U256, input: Bytes, params: &OfflineTxParams) -> Result<SignedTransaction> {
+        let signer = self.signer()?;
+        if params.chain_id == 0 {
+            return Err(anyhow::anyhow!("chain_id is required"));
+        }
+        if params.gas_limit < 21_000 {
+            return Err(anyhow::anyhow!("gas_limit must be at least 21000"));
+        }
+
+        let tx_type = match (params.tx_type, params.gas_price) {
+            (Some(tx_type), _) => tx_type,
+            (None, Some(_)) => TransactionType::Legacy,
+            (None, None) => TransactionType::Eip1559,
+        };
+        let envelope = match (tx_type, params.max_fee_per_gas, params.max_priority_fee_per_gas, params.gas_price) {
+            (TransactionType::Eip1559, Some(max_fee_per_gas), Some(max_priority_fee_per_gas), None) => {
+                if max_priority_fee_per_gas > max_fee_per_gas {
+                    return Err(anyhow::anyhow!("max_priority_fee_per_gas must not exceed max_fee_per_gas"));
+                }
+                let tx = TxEip1559 {
+                    chain_id: params.chain_id,
+                    nonce: params.nonce,
+                    gas_limit: params.gas_limit,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    to: TxKind::Call(to),
+                    value,
+                    input,
+                    ..Default::default()
+                };
+                let signature = signer.sign_hash(&tx.signature_hash()).await?;
+                TxEnvelope::from(tx.into_signed(signature))
+            }
+            (TransactionType::Eip2930, None, None, Some(gas_price)) => {
+                let tx = TxEip2930 {
+                    chain_id: params.chain_id,
+                    nonce: params.nonce,
+                    gas_price,
+                    gas_limit: params.gas_limit,
+                    to: TxKind::Call(to),
+                    value,
+                    input,
+                    ..Default::default()
+                };
+                let signature = signer.sign_hash(&tx.signature_hash()).await?;
+                TxEnvelope::from(tx.into_signed(signature))
+            }
+            (TransactionType::Legacy, None, None, Some(gas_price)) => {
+                let tx = TxLegacy {
+                    chain_id: Some(params.chain_id),
+                    nonce: params.nonce,
+                    gas_price,
+                    gas_limit: params.gas_limit,
+                    to: TxKind::Call(to),
+                    value,
+                    input,
+                };
+                let signature = signer.sign_hash(&tx.signature_hash()).await?;
+                TxEnvelope::from(tx.into_signed(signature))
+            }
+            (TransactionType::Eip1559, ..) => return Err(anyhow::anyhow!(
+                "EIP-1559 transactions need max_fee_per_gas and max_priority_fee_per_gas, and no gas_price"
+            )),
+            (tx_type, ..) => return Err(anyhow::anyhow!(
+                "{} transactions need gas_price, and no EIP-1559 fee fields",
+                tx_type.as_str()
+            )),
+        };
+
+        Ok(SignedTransaction {
+            from: signer.address().to_checksum(None),
+            to: to.to_checksum(None),
+            nonce: params.nonce,
+            chain_id: params.chain_id,
+            tx_type: tx_type.as_str().to_string(),
+            raw_transaction: format!("0x{}", hex::encode(envelope.encoded_2718())),
+            tx_hash: format!("{:#x}", envelope.tx_hash()),
+        })
+    }
+
+    pub fn decode_erc20_transfer(input: &[u8]) -> Option<(Address, U256)> {
+        if input.len() != 68 || input[..4] != ERC20_TRANSFER_SELECTOR || input[4..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        Some((Address::from_slice(&input[16..36]), U256::from_be_slice(&input[36..68])))
+    }
+
+    pub fn decode_raw_transaction(raw_transaction: &str) -> Result<DecodedTransaction> {
+        let raw = hex::decode(raw_transaction.trim().trim_start_matches("0x"))
+            .map_err(|e| anyhow::anyhow!("Invalid raw transaction hex: {}", e))?;
+        let mut buf = raw.as_slice();
+        let envelope = TxEnvelope::decode_2718(&mut buf)
+            .map_err(|e| anyhow::anyhow!("Invalid raw transaction: {}", e))?;
+        if !buf.is_empty() {
+            return Err(anyhow::anyhow!("Invalid raw transaction: {} trailing bytes", buf.len()));
+        }
+        let from = envelope.recover_signer()
+            .map_err(|e| anyhow::anyhow!("Failed to recover sender: {}", e))?;
+
+        let tx_type = match envelope.tx_type() {
+            TxType::Legacy => "legacy",
+            TxType::Eip2930 => "eip2930",
+            TxType::Eip1559 => "eip1559",
+            TxType::Eip4844 => "eip4844",
+            TxType::Eip7702 => "eip7702",
+        };
+        let to = envelope.to();
+        let erc20_transfer = to.and_then(|token| {
+            Self::decode_erc20_transfer(envelope.input()).map(|(recipient, amount)| DecodedErc20Transfer {
+                token_address: token.to_checksum(None),
+                to: recipient.to_checksum(None),
+                amount: amount.to_string(),
+            })
+        });
+        let is_dynamic_fee = envelope.is_dynamic_fee();
+
+        Ok(DecodedTransaction {
+            tx_type: tx_type.to_string(),
+            tx_hash: format!("{:#x}", envelope.tx_hash()),
+            from: from.to_checksum(None),
+            to: to.map(|address| address.to_checksum(None)),
+            value: envelope.value().to_string(),
+            chain_id: envelope.chain_id(),
+            nonce: envelope.nonce(),
+            gas_limit: envelope.gas_limit(),
+            gas_price: envelope.gas_price().map(|price| price.to_string()),
+            max_fee_per_gas: is_dynamic_fee.then(|| envelope.max_fee_per_gas().to_string()),
+            max_priority_fee_per_gas: envelope.max_priority_fee_per_gas().map(|fee| fee.to_string()),
+            input: format!("0x{}", hex::encode(envelope.input())),
+            erc20_transfer,
+            authorization_count: envelope.authorization_list().map(|list| list.len()),
+        })
+    }
+
+    pub async fn broadcast_raw_transaction(raw_transaction: &str, rpc_url: &str) -> Result<(TxHash, DecodedTransaction)> {
+        let decoded = Self::decode_raw_transaction(raw_transaction)?;
+        let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
+
+        if let Some(chain_id) = decoded.chain_id {
+            let node_chain_id = provider.get_chain_id().await?;
+            if chain_id != node_chain_id {
+                return Err(anyhow::anyhow!(
+                    "Transaction is for chain {} but the RPC endpoint is chain {}",
+                    chain_id,
+                    node_chain_id
+                ));
+            }
+        }
+
+        let raw = hex::decode(raw_transaction.trim().trim_start_matches("0x"))?;
+        let pending_tx = provider.send_raw_transaction(&raw).await?;
+        Ok((*pending_tx.tx_hash(), decoded))
+    }
+
+    pub async fn sweep(&self, to: &str, token_addresses: &[String], rpc_url: &str) -> Result<SweepResult> {
+        self.sweep_with_type(to, token_addresses, rpc_url, None).await
+    }
+
+    pub async fn sweep_with_type(
+        &self,
+        to: &str,
+        token_addresses: &[String],
+        rpc_url: &str,
+        tx_type: Option<TransactionType>,
+    ) -> Result<SweepResult> {
+        let from_address = self.signer()?.address();
+        let to_address = crate::utils::parse_address(to)?;
+        if to_address == from_address {
+            return Err(anyhow::anyhow!("Sweep destination must differ from the source address"));
+        }
+        let provider = ProviderBuilder::new()
+            .wallet(self.ethereum_wallet()?)
+            .connect_http(rpc_url.parse()?);
+
+        let token_options = TxOptions { tx_type, ..Default::default() };
+        let mut token_transfers = Vec::with_capacity(token_addresses.len());
+        let mut tx_hashes = Vec::new();
+        for token_address in token_addresses {
+            let balance = Self::get_erc20_balance(&self.address, token_address, rpc_url).await?;
+            if balance.is_zero() {
+                token_transfers.push(SweepTokenTransfer {
+                    token_address: token_address.clone(),
+                    amount: "0".to_string(),
+                    tx_hash: None,
+                });
+                continue;
+            }
+
+            // Each transfer must be mined before the next step so the native sweep sees the final balance.
+            let tx_hash = self.send_erc20_token_with_options(to, balance, token_address, rpc_url, &token_options).await
+                .map_err(|e| anyhow::anyhow!("Failed to sweep token {}: {} (already sent: {:?})", token_address, e, tx_hashes))?;
+            tx_hashes.push(format!("{:#x}", tx_hash));
+            let receipt = PendingTransactionBuilder::new(provider.root().clone(), tx_hash)
+                .with_timeout(Some(SWEEP_RECEIPT_TIMEOUT))
+                .get_receipt()
+                .await
+                .map_err(|e| anyhow::anyhow!("Token transfer {:#x} was not confirmed: {} (already sent: {:?})", tx_hash, e, tx_hashes))?;
+            if !receipt.status() {
+                return Err(anyhow::anyhow!("Token transfer {:#x} for {} reverted (already sent: {:?})", tx_hash, token_address, tx_hashes));
+            }
+            token_transfers.push(SweepTokenTransfer {
+                token_address: token_address.clone(),
+                amount: balance.to_string(),
+                tx_hash: Some(format!("{:#x}", tx_hash)),
+            });
+        }
+
+        let gas_limit = if provider.get_code_at(to_address).await?.is_empty() {
+            21_000
+        } else {
+            warn!("Sweep destination {} is a contract; unused gas is refunded to the source and may leave dust", to);
+            let estimate_tx = TransactionRequest::default()
+                .from(from_address)
+                .to(to_address)
+                .value(U256::from(1));
+            provider.estimate_gas(estimate_tx).await?
+        };
+        let (max_fee_per_gas, _, current_gas_price) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+        // With the priority fee equal to the max fee the effective gas price is exactly max_fee_per_gas,
+        // so the fee is known up front and balance - fee leaves the source at zero.
+        let fee = U256::from(gas_limit) * max_fee_per_gas;
+        // The price for that is the whole headroom over the current gas price, paid as tip.
+        let overpay = U256::from(gas_limit) * max_fee_per_gas.saturating_sub(current_gas_price);
+        info!(
+            "Sweep fixes the native fee at {} wei ({} wei/gas); about {} wei of it is above the current gas price of {} wei/gas",
+            fee, max_fee_per_gas, overpay, current_gas_price
+        );
+        if crate::utils::charges_l1_data_fee(rpc_url) {
+            warn!("{} charges an L1 data fee that the sweep fee does not include; the native transfer can be rejected for insufficient funds", rpc_url);
+        }
+        let balance = provider.get_balance(from_address).await?;
+
+        let (native_amount, native_tx_hash) = if balance > fee {
+            let amount = balance - fee;
+            let tx_type = crate::utils::resolve_transaction_type(tx_type, rpc_url).await;
+            let tx = TransactionRequest::default()
+                .to(to_address)
+                .value(amount)
+                .gas_limit(gas_limit);
+            let tx = crate::utils::apply_fee_fields(tx, tx_type, max_fee_per_gas, max_fee_per_gas, max_fee_per_gas)?;
+            let tx_hash = self.send_with_managed_nonce(&provider, tx, rpc_url, None).await
+                .map_err(|e| anyhow::anyhow!("Failed to sweep native balance: {} (already sent: {:?})", e, tx_hashes))?;
+            let tx_hash = format!("{:#x}", tx_hash);
+            tx_hashes.push(tx_hash.clone());
+            (amount, Some(tx_hash))
+        } else {
+            warn!("Native balance {} wei does not cover the sweep fee {} wei; leaving it in place", balance, fee);
+            (U256::ZERO, None)
+        };
+
+        Ok(SweepResult {
+            from: self.address.clone(),
+            to: to_address.to_checksum(None),
+            token_transfers,
+            native_amount: native_amount.to_string(),
+            native_fee: if native_tx_hash.is_some() { fee.to_string() } else { "0".to_string() },
+            native_tx_hash,
+            tx_hashes,
+        })
     }
 
     pub async fn get_native_balance(address: &str, rpc_url: &str) -> Result<U256> {
         let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
-        let addr = Address::from_str(address)?;
+        let addr = crate::utils::parse_address(address)?;
         let balance = provider.get_balance(addr).await?;
         Ok(balance)
     }
@@ -216,8 +1165,8 @@ impl EvmWallet {
     ) -> Result<U256> {
         let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
         
-        let token_addr = Address::from_str(token_address)?;
-        let user_addr = Address::from_str(address)?;
+        let token_addr = crate::utils::parse_address(token_address)?;
+        let user_addr = crate::utils::parse_address(address)?;
 
         let function_selector = "70a08231";
         let address_padded = format!("{:0>64}", format!("{:x}", user_addr));
@@ -236,42 +1185,54 @@ impl EvmWallet {
     pub async fn estimate_gas(
         &self,
         to: &str,
-        _amount_eth: U256,
+        amount_eth: U256,
+        rpc_url: &str,
+    ) -> Result<(u64, String, String)> {
+        self.estimate_gas_with_options(to, amount_eth, rpc_url, &TxOptions::default()).await
+    }
+
+    pub async fn estimate_gas_with_options(
+        &self,
+        to: &str,
+        amount: U256,
         rpc_url: &str,
+        options: &TxOptions,
     ) -> Result<(u64, String, String)> {
+        crate::utils::validate_tx_options(options, &crate::utils::configured_fee_caps())?;
         let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(self.signer.clone().unwrap()))
+            .wallet(self.ethereum_wallet()?)
             .connect_http(rpc_url.parse()?);
-        let to_address = Address::from_str(to)?;
-        let estimate_amount = U256::from(10u64.pow(16));
-
+        let to_address = crate::utils::parse_address(to)?;
+        let from_address = self.signer()?.address();
         
-        let tx = TransactionRequest::default()
-            .to(to_address)
-            .value(estimate_amount);
-        let gas_limit = provider.estimate_gas(tx).await?;
+        let gas_limit = match options.gas_limit {
+            Some(gas_limit) => gas_limit,
+            None => {
+                let tx = TransactionRequest::default()
+                    .from(from_address)
+                    .to(to_address)
+                    .value(amount);
+                provider.estimate_gas(tx).await?
+            }
+        };
         
-        let (max_fee_per_gas, priority_fee, base_fee) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+        let fees = FeeSettings::resolve(options, rpc_url).await;
+        let estimated_gas_price = fees.effective_gas_price();
         
-        let from_address = self.signer.as_ref().unwrap().address();
         let balance = provider.get_balance(from_address).await?;
+        let max_fee_per_gas = fees.max_price();
         let max_gas_cost = U256::from(gas_limit) * max_fee_per_gas;
-        let total_needed = estimate_amount + max_gas_cost;
+        let total_needed = amount + max_gas_cost;
         
         if balance < total_needed {
             return Err(anyhow::anyhow!(
                 "Insufficient funds: balance {} wei, needed {} wei (amount: {}, max gas cost: {})",
-                balance, total_needed, estimate_amount, max_gas_cost
+                balance, total_needed, amount, max_gas_cost
             ));
         }
         
-        let estimated_gas_price = if crate::utils::is_non_eip1559_network(rpc_url) {
-            base_fee
-        } else {
-            base_fee + priority_fee
-        };
         let total_fee = U256::from(gas_limit) * estimated_gas_price;
-        Ok((gas_limit as u64, estimated_gas_price.to_string(), total_fee.to_string()))
+        Ok((gas_limit, estimated_gas_price.to_string(), total_fee.to_string()))
     }
 
     pub async fn estimate_erc20_gas(
@@ -281,6 +1242,18 @@ impl EvmWallet {
         token_address: &str,
         rpc_url: &str,
     ) -> Result<(u64, String, String)> {
+        self.estimate_erc20_gas_with_options(to, amount_token_wei, token_address, rpc_url, &TxOptions::default()).await
+    }
+
+    pub async fn estimate_erc20_gas_with_options(
+        &self,
+        to: &str,
+        amount_token_wei: U256,
+        token_address: &str,
+        rpc_url: &str,
+        options: &TxOptions,
+    ) -> Result<(u64, String, String)> {
+        crate::utils::validate_tx_options(options, &crate::utils::configured_fee_caps())?;
         warn!("=== ERC20 Gas Estimation Started ===");
         warn!("RPC URL: {}", rpc_url);
         warn!("Token Address: {}", token_address);
@@ -288,40 +1261,46 @@ impl EvmWallet {
         warn!("Amount: {}", amount_token_wei);
         
         let provider = ProviderBuilder::new()
-            .wallet(EthereumWallet::from(self.signer.clone().unwrap()))
+            .wallet(self.ethereum_wallet()?)
             .connect_http(rpc_url.parse()?);
 
-        let token_addr = Address::from_str(token_address)?;
-        let to_address = Address::from_str(to)?;
+        let token_addr = crate::utils::parse_address(token_address)?;
+        let to_address = crate::utils::parse_address(to)?;
 
-        let function_selector = "a9059cbb";
-        let to_padded = format!("{:0>64}", format!("{:x}", to_address));
-        let amount_padded = format!("{:0>64x}", amount_token_wei);
-        
-        let data = format!("{}{}{}", function_selector, to_padded, amount_padded);
-        let call_data = Bytes::from(hex::decode(data)?);
+        let call_data = Self::erc20_transfer_calldata(to_address, amount_token_wei);
 
-        warn!("Estimating gas limit...");
-        let tx = TransactionRequest::default()
-            .to(token_addr)
-            .input(call_data.into());
+        let gas_limit = match options.gas_limit {
+            Some(gas_limit) => {
+                warn!("Using requested gas limit: {}", gas_limit);
+                gas_limit
+            }
+            None => {
+                warn!("Estimating gas limit...");
+                let tx = TransactionRequest::default()
+                    .to(token_addr)
+                    .input(call_data.into());
 
-        let gas_limit = match provider.estimate_gas(tx).await {
-            Ok(limit) => {
-                warn!("Gas limit estimated successfully: {}", limit);
-                limit as u64
-            },
-            Err(e) => {
-                warn!("Gas estimation failed: {}", e);
-                warn!("Error details: {:?}", e);
-                return Err(e.into());
+                match provider.estimate_gas(tx).await {
+                    Ok(limit) => {
+                        warn!("Gas limit estimated successfully: {}", limit);
+                        limit
+                    },
+                    Err(e) => {
+                        warn!("Gas estimation failed: {}", e);
+                        warn!("Error details: {:?}", e);
+                        return Err(e.into());
+                    }
+                }
             }
         };
         
-        let (max_fee_per_gas, priority_fee, base_fee) = crate::utils::get_eip1559_gas_price(rpc_url).await;
+        let fees = FeeSettings::resolve(options, rpc_url).await;
+        let estimated_gas_price = fees.effective_gas_price();
+        warn!("Transaction type: {}", fees.tx_type.as_str());
         
-        let from_address = self.signer.as_ref().unwrap().address();
+        let from_address = self.signer()?.address();
         let balance = provider.get_balance(from_address).await?;
+        let max_fee_per_gas = fees.max_price();
         let max_gas_cost = U256::from(gas_limit) * max_fee_per_gas;
         
         if balance < max_gas_cost {
@@ -331,16 +1310,9 @@ impl EvmWallet {
             ));
         }
         
-        let estimated_gas_price = if crate::utils::is_non_eip1559_network(rpc_url) {
-            warn!("Non-EIP-1559 network detected, using base_fee only");
-            base_fee
-        } else {
-            warn!("EIP-1559 network, using base_fee + priority_fee");
-            base_fee + priority_fee
-        };
-        warn!("Estimated gas price: {} wei (base: {}, priority: {})", estimated_gas_price, base_fee, priority_fee);        
+        warn!("Estimated gas price: {} wei (base: {}, priority: {})", estimated_gas_price, fees.gas_price, fees.max_priority_fee_per_gas);        
         let total_fee = U256::from(gas_limit) * estimated_gas_price;
-        Ok((gas_limit as u64, estimated_gas_price.to_string(), total_fee.to_string()))
+        Ok((gas_limit, estimated_gas_price.to_string(), total_fee.to_string()))
     }
 
     pub async fn get_erc20_transfer_events(
@@ -351,7 +1323,7 @@ impl EvmWallet {
         rpc_url: &str,
     ) -> Result<Vec<Erc20TransferEvent>> {
         let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
-        let token_addr = Address::from_str(token_address)?;
+        let token_addr = crate::utils::parse_address(token_address)?;
     
         let transfer_topic = FixedBytes::from_str("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")?;
         
@@ -368,7 +1340,7 @@ impl EvmWallet {
         }
         
         if let Some(addr_filter) = address_filter {
-            let filter_addr = Address::from_str(addr_filter)?;
+            let filter_addr = crate::utils::parse_address(addr_filter)?;
             let padded_addr = FixedBytes::from_slice(&[&[0u8; 12], filter_addr.as_slice()].concat());
             filter = filter.topic1(padded_addr);
         }
@@ -385,8 +1357,8 @@ impl EvmWallet {
                 let event = Erc20TransferEvent {
                     transaction_hash: format!("{:#x}", log.transaction_hash.unwrap_or_default()),
                     block_number: log.block_number.unwrap_or_default(),
-                    from_address: format!("{:#x}", from_addr),
-                    to_address: format!("{:#x}", to_addr),
+                    from_address: from_addr.to_checksum(None),
+                    to_address: to_addr.to_checksum(None),
                     amount: amount.to_string(),
                     log_index: log.log_index.unwrap_or_default(),
                 };
@@ -405,7 +1377,7 @@ impl EvmWallet {
         rpc_url: &str,
     ) -> Result<Vec<TransactionReceipt>> {
         let provider = ProviderBuilder::new().connect_http(rpc_url.parse()?);
-        let target_addr = Address::from_str(address)?;
+        let target_addr = crate::utils::parse_address(address)?;
         
         let latest_block = provider.get_block_number().await?;
         let from_block = from_block.unwrap_or(latest_block.saturating_sub(100));
@@ -420,7 +1392,7 @@ impl EvmWallet {
                 let block_timestamp = block.header.timestamp;
                 
                 if let Some(transactions) = block.transactions.as_transactions() {
-                    let tx_vec: Vec<_> = transactions.iter().cloned().collect();
+                    let tx_vec: Vec<_> = transactions.to_vec();
                     for tx in tx_vec {
                         if tx.value() > U256::ZERO {
                             let from_addr = tx.from();
@@ -435,7 +1407,7 @@ impl EvmWallet {
                                     } else {
                                         "failed".to_string()
                                     };
-                                    let gas_used = receipt.gas_used as u64;
+                                    let gas_used = receipt.gas_used;
                                     let effective_gas_price = receipt.effective_gas_price.to_string();
                                     
                                     let effective_gas_price_u128 = receipt.effective_gas_price;
@@ -455,11 +1427,11 @@ impl EvmWallet {
                                     let receipt = TransactionReceipt {
                                         transaction_hash: format!("{:#x}", tx_hash),
                                         block_number,
-                                        from_address: format!("{:#x}", from_addr),
-                                        to_address: format!("{:#x}", to_addr),
+                                        from_address: from_addr.to_checksum(None),
+                                        to_address: to_addr.to_checksum(None),
                                         amount: tx.value().to_string(),
                                         gas_used,
-                                        gas_limit: tx.gas_limit() as u64,
+                                        gas_limit: tx.gas_limit(),
                                         gas_price: effective_gas_price.clone(),
                                         effective_gas_price,
                                         transaction_fee: transaction_fee.to_string(),
@@ -505,7 +1477,7 @@ impl EvmWallet {
                 
                 let transactions = if let Some(txs) = block.transactions.as_transactions() {
                     debug!("Using as_transactions() - found {} transactions", txs.len());
-                    Some(txs.iter().cloned().collect())
+                    Some(txs.to_vec())
                 } else if let Some(tx_hashes) = block.transactions.as_hashes() {
                     debug!("Using as_hashes() - found {} transaction hashes", tx_hashes.len());
                     let mut txs = Vec::new();
@@ -535,7 +1507,7 @@ impl EvmWallet {
                             } else {
                                 "failed".to_string()
                             };
-                            let gas_used = receipt.gas_used as u64;
+                            let gas_used = receipt.gas_used;
                             let effective_gas_price = receipt.effective_gas_price.to_string();
                             
                             let effective_gas_price_u128 = receipt.effective_gas_price;
@@ -555,11 +1527,11 @@ impl EvmWallet {
                             let receipt_data = TransactionReceipt {
                                 transaction_hash: format!("{:#x}", tx_hash),
                                 block_number,
-                                from_address: format!("{:#x}", tx.from()),
-                                to_address: format!("{:#x}", tx.to().unwrap_or_default()),
+                                from_address: tx.from().to_checksum(None),
+                                to_address: tx.to().unwrap_or_default().to_checksum(None),
                                 amount: tx.value().to_string(),
                                 gas_used,
-                                gas_limit: tx.gas_limit() as u64,
+                                gas_limit: tx.gas_limit(),
                                 gas_price: effective_gas_price.clone(),
                                 effective_gas_price,
                                 transaction_fee: transaction_fee.to_string(),
@@ -569,7 +1541,7 @@ impl EvmWallet {
                                 status,
                             };
                             all_transactions.push(receipt_data);
-                            debug!("Added transaction {} to results", format!("{:#x}", tx_hash));
+                            debug!("Added transaction {:#x} to results", tx_hash);
                         } else {
                             debug!("No receipt found for transaction {:#x}", tx_hash);
                         }
@@ -604,7 +1576,7 @@ impl EvmWallet {
                 };
                 
                 let effective_gas_price = receipt.effective_gas_price.to_string();
-                let gas_used = receipt.gas_used as u64;
+                let gas_used = receipt.gas_used;
                 let transaction_fee = receipt.effective_gas_price * gas_used as u128;
                 
                 let burnt_fees = if let Ok(Some(block)) = provider.get_block_by_number(receipt.block_number.unwrap_or_default().into()).await {
@@ -621,11 +1593,11 @@ impl EvmWallet {
                 let receipt_data = TransactionReceipt {
                     transaction_hash: format!("{:#x}", tx.tx_hash()),
                     block_number: receipt.block_number.unwrap_or_default(),
-                    from_address: format!("{:#x}", tx.from()),
-                    to_address: format!("{:#x}", tx.to().unwrap_or_default()),
+                    from_address: tx.from().to_checksum(None),
+                    to_address: tx.to().unwrap_or_default().to_checksum(None),
                     amount: tx.value().to_string(),
                     gas_used,
-                    gas_limit: tx.gas_limit() as u64,
+                    gas_limit: tx.gas_limit(),
                     gas_price: effective_gas_price.clone(),
                     effective_gas_price,
                     transaction_fee: transaction_fee.to_string(),
@@ -647,4 +1619,53 @@ impl EvmWallet {
         let latest_block = provider.get_block_number().await?;
         Ok(latest_block)
     }
-} 
\ No newline at end of file
+}
+
+// Shared by mnemonic and xpub derivation: at most MAX_DERIVATION_COUNT indices, all below the hardened offset.
+pub(crate) fn derivation_range(start_index: u32, count: u32) -> Result<std::ops::RangeInclusive<u32>> {
+    if count == 0 || count > MAX_DERIVATION_COUNT {
+        return Err(anyhow::anyhow!("count must be between 1 and {}", MAX_DERIVATION_COUNT));
+    }
+    let end_index = start_index.checked_add(count - 1)
+        .filter(|end| *end < BIP32_HARDENED_OFFSET)
+        .ok_or_else(|| anyhow::anyhow!("Address index range exceeds the non-hardened limit ({})", BIP32_HARDENED_OFFSET - 1))?;
+    Ok(start_index..=end_index)
+}
+
+fn suggest_mnemonic_words(language: Language, word: &str) -> Vec<String> {
+    // Anything more than two edits longer than every wordlist entry cannot be within the distance cut-off.
+    let longest = language.word_list().iter().map(|candidate| candidate.chars().count()).max().unwrap_or(0);
+    if word.chars().count() > longest + 2 {
+        return Vec::new();
+    }
+    let prefix: String = word.chars().take(4).collect();
+    let mut candidates: Vec<(usize, &str)> = language
+        .word_list()
+        .iter()
+        .map(|candidate| {
+            let distance = if prefix.chars().count() == 4 && candidate.starts_with(&prefix) {
+                0
+            } else {
+                levenshtein_distance(word, candidate)
+            };
+            (distance, *candidate)
+        })
+        .filter(|(distance, _)| *distance <= 2)
+        .collect();
+    candidates.sort();
+    candidates.into_iter().take(3).map(|(_, candidate)| candidate.to_string()).collect()
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b_chars.len()]
+}
@@ -0,0 +1,101 @@
+use alloy::primitives::{TxHash, U256};
+use alloy::signers::utils::public_key_to_address;
+use anyhow::Result;
+use coins_bip32::{
+    enc::{MainnetEncoder, XKeyEncoder},
+    xkeys::{Parent, XPub},
+};
+use k256::ecdsa::VerifyingKey;
+use crate::types::{DerivedAddress, Erc20TransferEvent, TransactionReceipt};
+use crate::wallet::{derivation_range, EvmWallet, BIP32_HARDENED_OFFSET};
+
+#[derive(Debug, Clone)]
+pub struct WatchOnlyWallet {
+    pub xpub: String,
+    key: XPub,
+}
+
+impl WatchOnlyWallet {
+    pub fn from_xpub(xpub: &str) -> Result<Self> {
+        let key = MainnetEncoder::xpub_from_base58(xpub.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid xpub: {}", e))?;
+        Ok(WatchOnlyWallet {
+            xpub: xpub.trim().to_string(),
+            key,
+        })
+    }
+
+    pub fn derive_address(&self, index: u32) -> Result<String> {
+        if index >= BIP32_HARDENED_OFFSET {
+            return Err(anyhow::anyhow!("Hardened index {} cannot be derived from an xpub", index));
+        }
+        let child = self.key.derive_child(0)
+            .and_then(|external_chain| external_chain.derive_child(index))
+            .map_err(|e| anyhow::anyhow!("Failed to derive address index {}: {}", index, e))?;
+        let verifying_key: &VerifyingKey = child.as_ref();
+        Ok(public_key_to_address(verifying_key).to_checksum(None))
+    }
+
+    pub fn derive_addresses(&self, start_index: u32, count: u32) -> Result<Vec<DerivedAddress>> {
+        derivation_range(start_index, count)?
+            .map(|index| {
+                Ok(DerivedAddress {
+                    index,
+                    derivation_path: format!("M/0/{}", index),
+                    address: self.derive_address(index)?,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn get_native_balance(&self, index: u32, rpc_url: &str) -> Result<U256> {
+        let address = self.derive_address(index)?;
+        EvmWallet::get_native_balance(&address, rpc_url).await
+    }
+
+    pub async fn get_erc20_balance(&self, index: u32, token_address: &str, rpc_url: &str) -> Result<U256> {
+        let address = self.derive_address(index)?;
+        EvmWallet::get_erc20_balance(&address, token_address, rpc_url).await
+    }
+
+    pub async fn get_native_transactions_by_block_range(
+        &self,
+        index: u32,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+        rpc_url: &str,
+    ) -> Result<Vec<TransactionReceipt>> {
+        let address = self.derive_address(index)?;
+        EvmWallet::get_native_transactions_by_block_range(&address, from_block, to_block, rpc_url).await
+    }
+
+    pub async fn get_erc20_transfer_events(
+        &self,
+        index: u32,
+        token_address: &str,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+        rpc_url: &str,
+    ) -> Result<Vec<Erc20TransferEvent>> {
+        let address = self.derive_address(index)?;
+        EvmWallet::get_erc20_transfer_events(token_address, from_block, to_block, Some(&address), rpc_url).await
+    }
+
+    pub async fn send_native_coin(&self, _to: &str, _amount_wei: U256, _rpc_url: &str) -> Result<TxHash> {
+        Err(Self::signing_error())
+    }
+
+    pub async fn send_erc20_token(
+        &self,
+        _to: &str,
+        _amount_token_wei: U256,
+        _token_address: &str,
+        _rpc_url: &str,
+    ) -> Result<TxHash> {
+        Err(Self::signing_error())
+    }
+
+    fn signing_error() -> anyhow::Error {
+        anyhow::anyhow!("Watch-only wallet cannot sign: it was created from an xpub and holds no private key")
+    }
+}
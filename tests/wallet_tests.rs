@@ -6,25 +6,44 @@ fn setup_test_env() {
     env::set_var("RPC_ENDPOINT", "http://localhost:8545");
 }
 
+// 원격 서명 서비스 대역: RemoteSignRequest 를 받아 로컬 키로 해시에 서명
+async fn spawn_remote_signer(private_key: &str) -> String {
+    use alloy::signers::SignerSync;
+    use axum::{extract::State, routing::post, Json, Router};
+    
+    let signer: alloy::signers::local::PrivateKeySigner = private_key.parse().unwrap();
+    let app = Router::new()
+        .route("/sign", post(|State(signer): State<alloy::signers::local::PrivateKeySigner>, Json(request): Json<RemoteSignRequest>| async move {
+            let hash: alloy::primitives::B256 = request.hash.parse().unwrap();
+            let signature = signer.sign_hash_sync(&hash).unwrap();
+            Json(RemoteSignResponse { signature: format!("0x{}", hex::encode(signature.as_bytes())) })
+        }))
+        .with_state(signer);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}/sign", address)
+}
+
 #[tokio::test]
 async fn test_generate_mnemonic() {
     let mnemonic = EvmWallet::generate_mnemonic().unwrap();
-    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let words: Vec<&str> = mnemonic.expose_secret().split_whitespace().collect();
     assert_eq!(words.len(), 24);
 }
 
 #[tokio::test]
 async fn test_generate_mnemonic_with_different_word_counts() {
     let mnemonic_12 = EvmWallet::generate_mnemonic_with_words(12).unwrap();
-    let words_12: Vec<&str> = mnemonic_12.split_whitespace().collect();
+    let words_12: Vec<&str> = mnemonic_12.expose_secret().split_whitespace().collect();
     assert_eq!(words_12.len(), 12);
 
     let mnemonic_18 = EvmWallet::generate_mnemonic_with_words(18).unwrap();
-    let words_18: Vec<&str> = mnemonic_18.split_whitespace().collect();
+    let words_18: Vec<&str> = mnemonic_18.expose_secret().split_whitespace().collect();
     assert_eq!(words_18.len(), 18);
 
     let mnemonic_24 = EvmWallet::generate_mnemonic_with_words(24).unwrap();
-    let words_24: Vec<&str> = mnemonic_24.split_whitespace().collect();
+    let words_24: Vec<&str> = mnemonic_24.expose_secret().split_whitespace().collect();
     assert_eq!(words_24.len(), 24);
 
     let result = EvmWallet::generate_mnemonic_with_words(13);
@@ -34,21 +53,21 @@ async fn test_generate_mnemonic_with_different_word_counts() {
 #[tokio::test]
 async fn test_create_wallet_from_mnemonic() {
     let mnemonic = EvmWallet::generate_mnemonic().unwrap();
-    let wallet = EvmWallet::from_mnemonic(&mnemonic).unwrap();
+    let wallet = EvmWallet::from_mnemonic(mnemonic.expose_secret()).unwrap();
     
-    assert!(!wallet.private_key.is_empty());
+    assert!(!wallet.private_key.expose_secret().is_empty());
     assert!(!wallet.public_key.is_empty());
     assert!(!wallet.address.is_empty());
     assert!(wallet.address.starts_with("0x"));
-    assert_eq!(wallet.mnemonic, Some(mnemonic));
+    assert_eq!(wallet.mnemonic.as_ref().map(SecretString::expose_secret), Some(mnemonic.expose_secret()));
 }
 
 #[tokio::test]
 async fn test_create_wallet_from_private_key() {
     let original_wallet = EvmWallet::new_random().unwrap();
-    let restored_wallet = EvmWallet::create_wallet_from_private_key(&original_wallet.private_key).unwrap();
+    let restored_wallet = EvmWallet::create_wallet_from_private_key(original_wallet.private_key.expose_secret()).unwrap();
     
-    assert_eq!(original_wallet.private_key, restored_wallet.private_key);
+    assert_eq!(original_wallet.private_key.expose_secret(), restored_wallet.private_key.expose_secret());
     assert_eq!(original_wallet.address, restored_wallet.address);
     assert_eq!(original_wallet.public_key, restored_wallet.public_key);
 }
@@ -72,9 +91,9 @@ async fn test_address_from_private_key() {
 async fn test_address_from_private_key_vs_create_wallet() {
     let original_wallet = EvmWallet::new_random().unwrap();
     
-    let address_only = EvmWallet::address_from_private_key(&original_wallet.private_key).unwrap();
+    let address_only = EvmWallet::address_from_private_key(original_wallet.private_key.expose_secret()).unwrap();
     
-    let full_wallet = EvmWallet::create_wallet_from_private_key(&original_wallet.private_key).unwrap();
+    let full_wallet = EvmWallet::create_wallet_from_private_key(original_wallet.private_key.expose_secret()).unwrap();
     
     assert_eq!(address_only, full_wallet.address);
     assert_eq!(address_only, original_wallet.address);
@@ -131,7 +150,7 @@ async fn test_send_native_coin_with_private_key() {
     env::set_var("RPC_ENDPOINT", &provider_url);
     
     let sender_wallet = EvmWallet::new_random().unwrap();
-    let wallet = EvmWallet::create_wallet_from_private_key(&sender_wallet.private_key).unwrap();
+    let wallet = EvmWallet::create_wallet_from_private_key(sender_wallet.private_key.expose_secret()).unwrap();
     
     let recipient = "0x742d35Cc6634C0532925a3b8D55de0c4a2e6D6b4";
     let amount = U256::from(1000000000000000000u64);
@@ -156,7 +175,7 @@ async fn test_send_erc20_token_with_private_key() {
     env::set_var("RPC_ENDPOINT", &provider_url);
     
     let sender_wallet = EvmWallet::new_random().unwrap();
-    let wallet = EvmWallet::create_wallet_from_private_key(&sender_wallet.private_key).unwrap();
+    let wallet = EvmWallet::create_wallet_from_private_key(sender_wallet.private_key.expose_secret()).unwrap();
     
     let recipient = "0x742d35Cc6634C0532925a3b8D55de0c4a2e6D6b4";
     let token_address = "0x1234567890123456789012345678901234567890";
@@ -168,6 +187,193 @@ async fn test_send_erc20_token_with_private_key() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn test_sweep_native_balance_to_zero() {
+    let anvil = Anvil::new().spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let funder = EvmWallet::create_wallet_from_private_key(&hex::encode(anvil.keys()[0].to_bytes())).unwrap();
+    let source = EvmWallet::new_random().unwrap();
+    let destination = EvmWallet::new_random().unwrap();
+    
+    let funding = U256::from(1_234_567_890_123_456_789u64);
+    funder.send_native_coin(&source.address, funding, &rpc_url).await.unwrap();
+    assert_eq!(EvmWallet::get_native_balance(&source.address, &rpc_url).await.unwrap(), funding);
+    
+    assert!(source.sweep(&source.address, &[], &rpc_url).await.is_err());
+    
+    let result = source.sweep(&destination.address, &[], &rpc_url).await.unwrap();
+    assert_eq!(result.tx_hashes.len(), 1);
+    assert_eq!(result.native_tx_hash.as_ref(), Some(&result.tx_hashes[0]));
+    
+    let native_amount = U256::from_str_radix(&result.native_amount, 10).unwrap();
+    let native_fee = U256::from_str_radix(&result.native_fee, 10).unwrap();
+    assert_eq!(native_amount + native_fee, funding);
+    assert_eq!(EvmWallet::get_native_balance(&source.address, &rpc_url).await.unwrap(), U256::ZERO);
+    assert_eq!(EvmWallet::get_native_balance(&destination.address, &rpc_url).await.unwrap(), native_amount);
+}
+
+#[tokio::test]
+async fn test_broadcast_offline_signed_transaction() {
+    let anvil = Anvil::new().spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let sender = EvmWallet::create_wallet_from_private_key(&hex::encode(anvil.keys()[0].to_bytes())).unwrap();
+    let recipient = EvmWallet::new_random().unwrap();
+    let amount = U256::from(1_000_000_000_000_000u64);
+    let params = OfflineTxParams {
+        chain_id: anvil.chain_id(),
+        nonce: 0,
+        gas_limit: 21_000,
+        max_fee_per_gas: Some(10_000_000_000),
+        max_priority_fee_per_gas: Some(1_000_000_000),
+        ..Default::default()
+    };
+    let signed = sender.sign_native_transaction(&recipient.address, amount, &params).await.unwrap();
+    
+    let wrong_chain = OfflineTxParams { chain_id: 1, ..params.clone() };
+    let wrong_chain = sender.sign_native_transaction(&recipient.address, amount, &wrong_chain).await.unwrap();
+    assert!(EvmWallet::broadcast_raw_transaction(&wrong_chain.raw_transaction, &rpc_url).await.is_err());
+    
+    let (hash, decoded) = EvmWallet::broadcast_raw_transaction(&signed.raw_transaction, &rpc_url).await.unwrap();
+    assert_eq!(format!("{:#x}", hash), signed.tx_hash);
+    assert_eq!(decoded.from, sender.address);
+    
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert_eq!(EvmWallet::get_native_balance(&recipient.address, &rpc_url).await.unwrap(), amount);
+}
+
+#[tokio::test]
+async fn test_send_native_coin_with_remote_signer() {
+    let anvil = Anvil::new().spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let private_key = hex::encode(anvil.keys()[0].to_bytes());
+    let address = anvil.addresses()[0].to_checksum(None);
+    let url = spawn_remote_signer(&private_key).await;
+    let wallet = EvmWallet::from_signer(std::sync::Arc::new(RemoteSigner::new(&url, &address, None).unwrap()));
+    let recipient = EvmWallet::new_random().unwrap();
+    
+    let amount = U256::from(1_000_000_000_000_000u64);
+    assert!(wallet.estimate_gas(&recipient.address, amount, &rpc_url).await.is_ok());
+    wallet.send_native_coin(&recipient.address, amount, &rpc_url).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert_eq!(EvmWallet::get_native_balance(&recipient.address, &rpc_url).await.unwrap(), amount);
+}
+
+#[tokio::test]
+async fn test_concurrent_sends_get_distinct_nonces() {
+    let anvil = Anvil::new().spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let private_key = hex::encode(anvil.keys()[0].to_bytes());
+    let wallet = EvmWallet::create_wallet_from_private_key(&private_key).unwrap();
+    let recipient = EvmWallet::new_random().unwrap();
+    
+    let amount = U256::from(1_000_000_000_000u64);
+    let sends = (0..8).map(|_| wallet.send_native_coin(&recipient.address, amount, &rpc_url));
+    let results = futures::future::join_all(sends).await;
+    assert!(results.iter().all(|r| r.is_ok()));
+    
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert_eq!(EvmWallet::get_native_balance(&recipient.address, &rpc_url).await.unwrap(), amount * U256::from(8));
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmations() {
+    let anvil = Anvil::new().spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let private_key = hex::encode(anvil.keys()[0].to_bytes());
+    let wallet = EvmWallet::create_wallet_from_private_key(&private_key).unwrap();
+    let recipient = EvmWallet::new_random().unwrap();
+    
+    let amount = U256::from(1_000_000_000_000_000u64);
+    let hash = wallet.send_native_coin(&recipient.address, amount, &rpc_url).await.unwrap();
+    let confirmed = EvmWallet::wait_for_confirmations(hash, 1, std::time::Duration::from_secs(10), &rpc_url).await
+        .unwrap()
+        .expect("transaction should be mined");
+    assert!(confirmed.success);
+    assert!(confirmed.block_number > 0);
+    assert_eq!(confirmed.gas_used, 21000);
+    assert_eq!(confirmed.transaction_fee, U256::from(confirmed.effective_gas_price) * U256::from(21000));
+    
+    // Anvil only mines on demand, so a deeper confirmation never arrives.
+    let deeper = EvmWallet::wait_for_confirmations(hash, 3, std::time::Duration::from_secs(1), &rpc_url).await.unwrap();
+    assert!(deeper.is_none());
+}
+
+#[tokio::test]
+async fn test_speed_up_and_cancel_pending_transaction() {
+    let anvil = Anvil::new().arg("--no-mining").spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let private_key = hex::encode(anvil.keys()[0].to_bytes());
+    let wallet = EvmWallet::create_wallet_from_private_key(&private_key).unwrap();
+    let recipient = EvmWallet::new_random().unwrap();
+    
+    let amount = U256::from(1_000_000_000_000_000u64);
+    let hash = wallet.send_native_coin(&recipient.address, amount, &rpc_url).await.unwrap();
+    let original_hash = format!("{:#x}", hash);
+    
+    let sped_up = wallet.replace_transaction(&original_hash, ReplacementKind::SpeedUp, &rpc_url).await.unwrap();
+    assert_eq!(sped_up.original_hash, original_hash);
+    assert_ne!(sped_up.hash, original_hash);
+    assert_eq!(sped_up.nonce, 0);
+    
+    let cancelled = wallet.replace_transaction(&sped_up.hash, ReplacementKind::Cancel, &rpc_url).await.unwrap();
+    assert_eq!(cancelled.nonce, 0);
+    let sped_up_fee: u128 = sped_up.max_fee_per_gas.unwrap().parse().unwrap();
+    let cancel_fee: u128 = cancelled.max_fee_per_gas.unwrap().parse().unwrap();
+    assert!(cancel_fee >= bump_replacement_fee(sped_up_fee));
+    
+    let provider = alloy::providers::ProviderBuilder::new().connect_http(rpc_url.parse().unwrap());
+    alloy::providers::ext::AnvilApi::anvil_mine(&provider, Some(1), None).await.unwrap();
+    // Only the cancel was mined, so the transfer never happened.
+    assert_eq!(EvmWallet::get_native_balance(&recipient.address, &rpc_url).await.unwrap(), U256::ZERO);
+    let result = wallet.replace_transaction(&cancelled.hash, ReplacementKind::SpeedUp, &rpc_url).await;
+    assert!(matches!(result, Err(ReplacementError::NotReplaceable(_))));
+    let result = wallet.replace_transaction("0x1234", ReplacementKind::Cancel, &rpc_url).await;
+    assert!(matches!(result, Err(ReplacementError::InvalidHash(_))));
+}
+
+#[tokio::test]
+async fn test_send_with_fee_and_nonce_overrides() {
+    let anvil = Anvil::new().spawn();
+    let rpc_url = anvil.endpoint();
+    
+    let private_key = hex::encode(anvil.keys()[0].to_bytes());
+    let wallet = EvmWallet::create_wallet_from_private_key(&private_key).unwrap();
+    let recipient = EvmWallet::new_random().unwrap();
+    let amount = U256::from(1_000_000_000_000_000u64);
+    
+    let options = TxOptions {
+        gas_limit: Some(30_000),
+        gas_price: Some(3_000_000_000),
+        nonce: Some(0),
+        ..Default::default()
+    };
+    let hash = wallet.send_native_coin_with_options(&recipient.address, amount, &rpc_url, &options).await.unwrap();
+    
+    let provider = alloy::providers::ProviderBuilder::new().connect_http(rpc_url.parse().unwrap());
+    let tx = alloy::providers::Provider::get_transaction_by_hash(&provider, hash).await.unwrap().unwrap();
+    assert_eq!(alloy::consensus::Transaction::gas_limit(&tx), 30_000);
+    assert_eq!(alloy::consensus::Transaction::gas_price(&tx), Some(3_000_000_000));
+    assert_eq!(alloy::consensus::Transaction::nonce(&tx), 0);
+    
+    // The managed nonce continues after the explicit one.
+    let hash = wallet.send_native_coin(&recipient.address, amount, &rpc_url).await.unwrap();
+    let tx = alloy::providers::Provider::get_transaction_by_hash(&provider, hash).await.unwrap().unwrap();
+    assert_eq!(alloy::consensus::Transaction::nonce(&tx), 1);
+    
+    // A gapped nonce sits in the queue and must not drag the managed nonce past it.
+    let options = TxOptions { nonce: Some(10), ..Default::default() };
+    wallet.send_native_coin_with_options(&recipient.address, amount, &rpc_url, &options).await.unwrap();
+    let hash = wallet.send_native_coin(&recipient.address, amount, &rpc_url).await.unwrap();
+    let tx = alloy::providers::Provider::get_transaction_by_hash(&provider, hash).await.unwrap().unwrap();
+    assert_eq!(alloy::consensus::Transaction::nonce(&tx), 2);
+}
+
 #[tokio::test]
 async fn test_estimate_gas_with_private_key() {
     let anvil = Anvil::new().spawn();
@@ -175,8 +381,7 @@ async fn test_estimate_gas_with_private_key() {
 
     env::set_var("RPC_ENDPOINT", &provider_url);
     
-    let sender_wallet = EvmWallet::new_random().unwrap();
-    let wallet = EvmWallet::create_wallet_from_private_key(&sender_wallet.private_key).unwrap();
+    let wallet = EvmWallet::create_wallet_from_private_key(&hex::encode(anvil.keys()[0].to_bytes())).unwrap();
     
     let recipient = "0x742d35Cc6634C0532925a3b8D55de0c4a2e6D6b4";
     let amount = U256::from(1000000000000000000u64);
@@ -190,6 +395,10 @@ async fn test_estimate_gas_with_private_key() {
     assert!(gas_limit <= 21000);
     assert!(!gas_price.is_empty());
     assert!(!total_fee.is_empty());
+    
+    // The requested amount is part of the estimate, so more than the balance cannot be estimated.
+    let balance = EvmWallet::get_native_balance(&wallet.address, &rpc_url).await.unwrap();
+    assert!(wallet.estimate_gas(recipient, balance, &rpc_url).await.is_err());
 }
 
 #[tokio::test]
@@ -199,11 +408,986 @@ async fn test_mnemonic_deterministic() {
     let wallet1 = EvmWallet::from_mnemonic(mnemonic).unwrap();
     let wallet2 = EvmWallet::from_mnemonic(mnemonic).unwrap();
     
-    assert_eq!(wallet1.private_key, wallet2.private_key);
+    assert_eq!(wallet1.private_key.expose_secret(), wallet2.private_key.expose_secret());
     assert_eq!(wallet1.address, wallet2.address);
     assert_eq!(wallet1.public_key, wallet2.public_key);
 }
 
+#[tokio::test]
+async fn test_mnemonic_bip44_derivation() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    
+    let wallet = EvmWallet::from_mnemonic(mnemonic).unwrap();
+    assert_eq!(wallet.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+    assert_eq!(wallet.derivation_path.as_deref(), Some("m/44'/60'/0'/0/0"));
+    
+    let wallet_index_1 = EvmWallet::from_mnemonic_with_index(mnemonic, 0, 1).unwrap();
+    assert_eq!(wallet_index_1.address, "0x6Fac4D18c912343BF86fa7049364Dd4E424Ab9C0");
+    assert_eq!(wallet_index_1.derivation_path.as_deref(), Some("m/44'/60'/0'/0/1"));
+    
+    let wallet_account_1 = EvmWallet::from_mnemonic_with_index(mnemonic, 1, 0).unwrap();
+    assert_ne!(wallet_account_1.address, wallet.address);
+    assert_eq!(wallet_account_1.derivation_path.as_deref(), Some("m/44'/60'/1'/0/0"));
+    
+    // 2^31 이상은 hardened 범위이므로 보고된 경로와 실제 키가 달라짐 → 거부
+    let last = EvmWallet::from_mnemonic_with_index(mnemonic, 0x7fff_ffff, 0x7fff_ffff).unwrap();
+    assert_eq!(last.derivation_path.as_deref(), Some("m/44'/60'/2147483647'/0/2147483647"));
+    assert!(EvmWallet::from_mnemonic_with_index(mnemonic, 0, 0x8000_0000).is_err());
+    assert!(EvmWallet::from_mnemonic_with_index(mnemonic, 0x8000_0000, 0).is_err());
+    assert!(EvmWallet::account_xpub(mnemonic, "", 0x8000_0000).is_err());
+}
+
+#[tokio::test]
+async fn test_mnemonic_with_passphrase() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    
+    let without_passphrase = EvmWallet::from_mnemonic(mnemonic).unwrap();
+    let empty_passphrase = EvmWallet::from_mnemonic_with_passphrase(mnemonic, "", 0, 0).unwrap();
+    assert_eq!(without_passphrase.address, empty_passphrase.address);
+    
+    let with_passphrase = EvmWallet::from_mnemonic_with_passphrase(mnemonic, "TREZOR", 0, 0).unwrap();
+    let with_passphrase_again = EvmWallet::from_mnemonic_with_passphrase(mnemonic, "TREZOR", 0, 0).unwrap();
+    assert_ne!(with_passphrase.address, without_passphrase.address);
+    assert_eq!(with_passphrase.address, with_passphrase_again.address);
+    assert_eq!(with_passphrase.mnemonic.as_ref().map(SecretString::expose_secret), Some(mnemonic));
+    
+    let legacy = EvmWallet::from_mnemonic_legacy(mnemonic, "TREZOR").unwrap();
+    assert_eq!(legacy.private_key.expose_secret(), "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553");
+    let legacy_english = EvmWallet::from_mnemonic_legacy_in(Some(bip39::Language::English), mnemonic, "TREZOR").unwrap();
+    assert_eq!(legacy_english.address, legacy.address);
+    assert!(EvmWallet::from_mnemonic_legacy_in(Some(bip39::Language::Japanese), mnemonic, "TREZOR").is_err());
+}
+
+#[tokio::test]
+async fn test_derive_addresses_and_xpub() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    
+    let addresses = EvmWallet::derive_addresses(mnemonic, "", 0, 0, 3).unwrap();
+    assert_eq!(addresses.len(), 3);
+    for derived in &addresses {
+        let wallet = EvmWallet::from_mnemonic_with_index(mnemonic, 0, derived.index).unwrap();
+        assert_eq!(derived.address, wallet.address);
+        assert_eq!(Some(derived.derivation_path.clone()), wallet.derivation_path);
+    }
+    
+    let offset = EvmWallet::derive_addresses(mnemonic, "", 0, 1, 1).unwrap();
+    assert_eq!(offset[0].index, 1);
+    assert_eq!(offset[0].address, addresses[1].address);
+    
+    let xpub = EvmWallet::account_xpub(mnemonic, "", 0).unwrap();
+    assert_eq!(xpub, "xpub6DCoCpSuQZB2jawqnGMEPS63ePKWkwWPH4TU45Q7LPXWuNd8TMtVxRrgjtEshuqpK3mdhaWHPFsBngh5GFZaM6si3yZdUsT8ddYM3PwnATt");
+    
+    assert!(EvmWallet::derive_addresses(mnemonic, "", 0, 0, 0).is_err());
+    assert!(EvmWallet::derive_addresses(mnemonic, "", 0, 0, MAX_DERIVATION_COUNT + 1).is_err());
+    assert!(EvmWallet::derive_addresses(mnemonic, "", 0, 0x7fff_ffff, 2).is_err());
+}
+
+#[tokio::test]
+async fn test_watch_only_wallet_from_xpub() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let xpub = EvmWallet::account_xpub(mnemonic, "", 0).unwrap();
+    
+    let watch_only = WatchOnlyWallet::from_xpub(&xpub).unwrap();
+    let expected = EvmWallet::derive_addresses(mnemonic, "", 0, 0, 5).unwrap();
+    let derived = watch_only.derive_addresses(0, 5).unwrap();
+    for (derived, expected) in derived.iter().zip(expected.iter()) {
+        assert_eq!(derived.address, expected.address);
+    }
+    assert_eq!(watch_only.derive_address(3).unwrap(), expected[3].address);
+    
+    assert!(watch_only.derive_address(0x8000_0000).is_err());
+    assert!(WatchOnlyWallet::from_xpub("xpub-invalid").is_err());
+    
+    let result = watch_only.send_native_coin("0x742d35Cc6634C0532925a3b8D55de0c4a2e6D6b4", U256::from(1u64), "http://localhost:8545").await;
+    assert!(result.unwrap_err().to_string().contains("Watch-only wallet cannot sign"));
+}
+
+#[tokio::test]
+async fn test_keystore_round_trip() {
+    let wallet = EvmWallet::new_random().unwrap();
+    
+    for kdf in [KeystoreKdf::Scrypt { n: 1024, r: 8, p: 1 }, KeystoreKdf::Pbkdf2 { c: 1000 }] {
+        let keystore = wallet.encrypt_keystore("correct horse", kdf).unwrap();
+        assert_eq!(keystore.version, 3);
+        assert_eq!(format!("0x{}", keystore.address.clone().unwrap()), wallet.address.to_lowercase());
+        
+        let json = serde_json::to_string(&keystore).unwrap();
+        let restored = EvmWallet::from_keystore_json(&json, "correct horse").unwrap();
+        assert_eq!(restored.private_key.expose_secret(), wallet.private_key.expose_secret());
+        assert_eq!(restored.address, wallet.address);
+        
+        let wrong_password = EvmWallet::from_keystore(&keystore, "wrong password");
+        assert!(wrong_password.unwrap_err().to_string().contains("MAC mismatch"));
+    }
+    
+    // Work-scaling parameters from an untrusted keystore are rejected before any key derivation.
+    let keystore = serde_json::to_value(wallet.encrypt_keystore("correct horse", KeystoreKdf::Scrypt { n: 1024, r: 8, p: 1 }).unwrap()).unwrap();
+    for (param, value) in [("r", 1u64 << 20), ("r", 0), ("p", 17), ("dklen", 1 << 40), ("dklen", 16), ("n", 1 << 21)] {
+        let mut tampered = keystore.clone();
+        tampered["crypto"]["kdfparams"][param] = serde_json::json!(value);
+        let error = EvmWallet::from_keystore_json(&tampered.to_string(), "correct horse").unwrap_err().to_string();
+        assert!(error.contains("Unsupported"), "{}={}: {}", param, value, error);
+    }
+    let mut tampered = keystore.clone();
+    tampered["crypto"]["kdfparams"]["n"] = serde_json::json!(1 << 20);
+    tampered["crypto"]["kdfparams"]["r"] = serde_json::json!(16);
+    assert!(EvmWallet::from_keystore_json(&tampered.to_string(), "correct horse").unwrap_err().to_string().contains("128 * n * r * p"));
+    // 기본값(n=2^18, r=8, p=1)의 두 배인 512 MiB는 거부
+    let mut tampered = keystore.clone();
+    tampered["crypto"]["kdfparams"]["n"] = serde_json::json!(1 << 19);
+    assert!(EvmWallet::from_keystore_json(&tampered.to_string(), "correct horse").unwrap_err().to_string().contains("128 * n * r * p"));
+}
+
+#[tokio::test]
+async fn test_keystore_spec_test_vector() {
+    let keystore_json = r#"{"crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"6087dab2f9fdbbfaddc31a909735c1e6"},"ciphertext":"5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46","kdf":"pbkdf2","kdfparams":{"c":262144,"dklen":32,"prf":"hmac-sha256","salt":"ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"},"mac":"517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"},"id":"3198bc9c-6672-5ab3-d995-4942343ae5b6","version":3}"#;
+    
+    let wallet = EvmWallet::from_keystore_json(keystore_json, "testpassword").unwrap();
+    assert_eq!(wallet.private_key.expose_secret(), "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d");
+}
+
+#[tokio::test]
+async fn test_wallet_vault_persistence() {
+    let dir = env::temp_dir().join(format!("evm-wallet-vault-{}", uuid::Uuid::new_v4()));
+    let kdf = KeystoreKdf::Scrypt { n: 1024, r: 8, p: 1 };
+    let test_private_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    
+    let vault = WalletVault::open_with_kdf(&dir, "master secret", kdf).unwrap();
+    let created = vault.create_wallet().unwrap();
+    let imported = vault.import_private_key(test_private_key).unwrap();
+    assert_eq!(vault.list().len(), 2);
+    assert_eq!(vault.get_wallet(&imported.wallet_id).unwrap().address, imported.address);
+    
+    let stored_file = std::fs::read_to_string(dir.join(format!("{}.json", imported.wallet_id))).unwrap();
+    assert!(!stored_file.contains("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let metadata = std::fs::metadata(dir.join(format!("{}.json", imported.wallet_id))).unwrap();
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+    }
+    
+    let reopened = WalletVault::open_with_kdf(&dir, "master secret", kdf).unwrap();
+    assert_eq!(reopened.get_wallet(&created.wallet_id).unwrap().address, created.address);
+    assert_eq!(reopened.get_wallet(&imported.wallet_id).unwrap().address, imported.address);
+    // wallet_id로 꺼낸 vault 지갑도 keystore로 내보낼 수 있어야 함
+    let exported = reopened.get_wallet(&imported.wallet_id).unwrap().encrypt_keystore("export pw", kdf).unwrap();
+    assert_eq!(EvmWallet::from_keystore(&exported, "export pw").unwrap().address, imported.address);
+    
+    assert!(WalletVault::open_with_kdf(&dir, "wrong secret", kdf).is_err());
+    
+    reopened.delete(&created.wallet_id).unwrap();
+    assert!(reopened.get_wallet(&created.wallet_id).is_err());
+    assert!(reopened.delete(&created.wallet_id).is_err());
+    assert_eq!(WalletVault::open_with_kdf(&dir, "master secret", kdf).unwrap().list().len(), 1);
+    
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_mnemonic_languages() {
+    use evm_wallet::utils::parse_mnemonic_language;
+    
+    for name in ["korean", "japanese", "spanish", "chinese_simplified", "chinese_traditional"] {
+        let language = parse_mnemonic_language(name).unwrap();
+        let mnemonic = EvmWallet::generate_mnemonic_in(language, 12).unwrap();
+        assert_eq!(mnemonic.expose_secret().split_whitespace().count(), 12);
+        
+        let explicit = EvmWallet::from_mnemonic_in(Some(language), mnemonic.expose_secret(), "", 0, 0).unwrap();
+        let detected = EvmWallet::from_mnemonic(mnemonic.expose_secret()).unwrap();
+        assert_eq!(explicit.address, detected.address, "auto-detection failed for {}", name);
+        
+        if language != bip39::Language::SimplifiedChinese && language != bip39::Language::TraditionalChinese {
+            assert!(EvmWallet::from_mnemonic_in(Some(bip39::Language::English), mnemonic.expose_secret(), "", 0, 0).is_err());
+        }
+    }
+    
+    assert!(parse_mnemonic_language("klingon").is_err());
+    assert_eq!(parse_mnemonic_language("Chinese-Simplified").unwrap(), bip39::Language::SimplifiedChinese);
+}
+
+#[tokio::test]
+async fn test_validate_mnemonic() {
+    let valid = EvmWallet::validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", None);
+    assert!(valid.valid);
+    assert_eq!(valid.word_count, 12);
+    assert_eq!(valid.language, "english");
+    assert_eq!(valid.checksum_valid, Some(true));
+    assert!(valid.unknown_words.is_empty());
+    
+    let bad_checksum = EvmWallet::validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon", None);
+    assert!(!bad_checksum.valid);
+    assert_eq!(bad_checksum.checksum_valid, Some(false));
+    
+    let typo = EvmWallet::validate_mnemonic("abandon abandon abandon abandon abandn abandon abandon abandon abandon abandon abandon abuot", None);
+    assert!(!typo.valid);
+    assert_eq!(typo.checksum_valid, None);
+    assert_eq!(typo.unknown_words.len(), 2);
+    assert_eq!(typo.unknown_words[0].position, 5);
+    assert_eq!(typo.unknown_words[0].word, "abandn");
+    assert_eq!(typo.unknown_words[0].suggestions[0], "abandon");
+    assert_eq!(typo.unknown_words[1].position, 12);
+    assert!(typo.unknown_words[1].suggestions.contains(&"about".to_string()));
+    
+    let short = EvmWallet::validate_mnemonic("abandon abandon abandon", None);
+    assert!(!short.word_count_valid);
+    assert_eq!(short.word_count, 3);
+    assert_eq!(short.checksum_valid, None);
+    
+    let oversized = EvmWallet::validate_mnemonic(&vec!["abandn"; MAX_MNEMONIC_WORDS + 1].join(" "), None);
+    assert!(!oversized.word_count_valid);
+    assert!(oversized.unknown_words.iter().all(|word| word.suggestions.is_empty()));
+    let long_word = EvmWallet::validate_mnemonic(&"a".repeat(10_000), None);
+    assert!(long_word.unknown_words[0].suggestions.is_empty());
+}
+
+#[tokio::test]
+async fn test_slip39_spec_vectors() {
+    // SLIP-0039 공식 테스트 벡터 (passphrase "TREZOR")
+    let single = vec![SecretString::from("duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard")];
+    assert_eq!(hex::encode(combine_slip39_shares(&single, "TREZOR").unwrap()), "bb54aac4b89dc868ba37d9cc21b2cece");
+    
+    let two_of_three = vec![
+        SecretString::from("shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed"),
+        SecretString::from("shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking"),
+    ];
+    assert_eq!(hex::encode(combine_slip39_shares(&two_of_three, "TREZOR").unwrap()), "b43ceb7e57a0ea8766221624d01b0864");
+    
+    assert!(combine_slip39_shares(&two_of_three[..1], "TREZOR").is_err());
+    
+    let bad_checksum = vec![SecretString::from("duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney")];
+    assert!(combine_slip39_shares(&bad_checksum, "TREZOR").is_err());
+}
+
+#[tokio::test]
+async fn test_slip39_split_and_recover_wallet() {
+    let mnemonic = EvmWallet::generate_mnemonic_with_words(24).unwrap();
+    let original = EvmWallet::from_mnemonic(mnemonic.expose_secret()).unwrap();
+    
+    let shares = EvmWallet::split_mnemonic_slip39(mnemonic.expose_secret(), None, "officers", 3, 5).unwrap();
+    assert_eq!(shares.len(), 5);
+    assert_eq!(shares[0].expose_secret().split_whitespace().count(), 33);
+    
+    let recovered = EvmWallet::from_slip39_shares(&shares[2..], "officers", bip39::Language::English, 15).unwrap();
+    assert_eq!(recovered.address, original.address);
+    assert_eq!(recovered.mnemonic.as_ref().map(SecretString::expose_secret), Some(mnemonic.expose_secret()));
+    
+    let other_subset = vec![shares[4].clone(), shares[0].clone(), shares[3].clone()];
+    assert_eq!(EvmWallet::from_slip39_shares(&other_subset, "officers", bip39::Language::English, 15).unwrap().address, original.address);
+    
+    assert!(EvmWallet::from_slip39_shares(&shares[..2], "officers", bip39::Language::English, 15).is_err());
+    // 생성된 share의 iteration exponent(1)가 허용 한도보다 크면 PBKDF2 전에 거부
+    assert!(EvmWallet::from_slip39_shares(&shares[2..], "officers", bip39::Language::English, 0).is_err());
+    assert!(EvmWallet::split_mnemonic_slip39(mnemonic.expose_secret(), None, "", 4, 3).is_err());
+    assert!(EvmWallet::split_mnemonic_slip39(mnemonic.expose_secret(), None, "", 1, 3).is_err());
+}
+
+#[tokio::test]
+async fn test_vanity_address_search() {
+    let mut options = VanityOptions::new("0xA", "e");
+    options.threads = 2;
+    options.timeout = std::time::Duration::from_secs(60);
+    let mut progress_reports = 0;
+    let result = search_vanity_address(&options, |_| progress_reports += 1).unwrap();
+    
+    assert!(result.wallet.address.starts_with("0xA"));
+    assert!(result.wallet.address.ends_with('e'));
+    assert!(result.attempts >= 1);
+    assert!(result.wallet.signer.is_some());
+    assert_eq!(EvmWallet::address_from_private_key(result.wallet.private_key.expose_secret()).unwrap(), result.wallet.address);
+    
+    let mut insensitive = VanityOptions::new("ab", "");
+    insensitive.case_sensitive = false;
+    let found = search_vanity_address(&insensitive, |_| {}).unwrap();
+    assert!(found.wallet.address.to_lowercase().trim_start_matches("0x").starts_with("ab"));
+    
+    assert!(VanityOptions::new("0xZZ", "").validate().is_err());
+    assert!(matches!(search_vanity_address(&VanityOptions::new("0xZZ", ""), |_| {}), Err(VanityError::Invalid(_))));
+    assert!(VanityOptions::new("", "").validate().is_err());
+    let mut too_many_threads = VanityOptions::new("ab", "");
+    too_many_threads.threads = 100_000;
+    assert!(too_many_threads.validate().is_err());
+    too_many_threads.threads = MAX_VANITY_THREADS;
+    assert!(too_many_threads.validate().is_ok());
+    
+    let mut impossible = VanityOptions::new("ffffffffffffffffffff", "");
+    impossible.threads = 1;
+    impossible.timeout = std::time::Duration::from_secs(1);
+    impossible.progress_interval = std::time::Duration::from_millis(200);
+    let mut reports = Vec::new();
+    assert!(matches!(search_vanity_address(&impossible, |progress| reports.push(progress.attempts)), Err(VanityError::TimedOut(_))));
+    assert!(!reports.is_empty());
+}
+
+#[tokio::test]
+async fn test_secrets_are_redacted_in_debug_output() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let wallet = EvmWallet::from_mnemonic(mnemonic).unwrap();
+    let private_key = wallet.private_key.expose_secret().to_string();
+    
+    let debug = format!("{:?}", wallet);
+    assert!(!debug.contains(&private_key));
+    assert!(!debug.contains("abandon"));
+    assert!(debug.contains("[REDACTED]"));
+    assert!(debug.contains(&wallet.address));
+    
+    let request: SendTransactionRequest = serde_json::from_value(serde_json::json!({
+        "to": "0x0000000000000000000000000000000000000001",
+        "amount": "1.0",
+        "private_key": private_key,
+    })).unwrap();
+    assert!(!format!("{:?}", request).contains(&private_key));
+    assert_eq!(request.private_key.as_ref().map(SecretString::expose_secret), Some(private_key.as_str()));
+    
+    // 응답 직렬화에서는 그대로 노출되어야 함
+    let serialized = serde_json::to_value(&wallet).unwrap();
+    assert_eq!(serialized["private_key"], private_key);
+    assert_eq!(serialized["mnemonic"], mnemonic);
+}
+
+#[tokio::test]
+async fn test_eip55_checksum_addresses() {
+    // EIP-55 스펙 테스트 벡터
+    let vectors = [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+    for vector in vectors {
+        let address = parse_address_strict(vector).unwrap();
+        assert_eq!(address.to_checksum(None), vector);
+        assert!(parse_address_strict(&vector.to_lowercase()).is_ok());
+        assert!(parse_address_strict(&format!("0x{}", vector[2..].to_uppercase())).is_ok());
+    }
+    
+    assert!(parse_address_strict("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").is_err());
+    assert!(parse_address_strict("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    assert!(parse_address_strict("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    assert!(parse_address_strict("0x1234").is_err());
+    
+    let wallet = EvmWallet::new_random().unwrap();
+    assert_eq!(parse_address_strict(&wallet.address).unwrap().to_checksum(None), wallet.address);
+}
+
+#[tokio::test]
+async fn test_public_key_forms_and_address_from_public_key() {
+    let private_key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    let wallet = EvmWallet::create_wallet_from_private_key(private_key).unwrap();
+    
+    let compressed = wallet.compressed_public_key().unwrap();
+    assert_eq!(compressed.len(), 66);
+    assert!(compressed.starts_with("02") || compressed.starts_with("03"));
+    assert_eq!(&compressed[2..], &wallet.public_key[2..66]);
+    
+    let info = EvmWallet::public_key_info(&wallet.public_key).unwrap();
+    assert_eq!(info.address, "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+    assert_eq!(info.public_key, wallet.public_key);
+    assert_eq!(info.compressed_public_key, compressed);
+    
+    // 압축/비압축/0x 접두사/04 없는 64바이트 형식 모두 같은 주소
+    for input in [
+        compressed.clone(),
+        wallet.public_key.clone(),
+        format!("0x{}", compressed),
+        wallet.public_key[2..].to_string(),
+    ] {
+        assert_eq!(EvmWallet::address_from_public_key(&input).unwrap(), wallet.address);
+    }
+    
+    assert!(EvmWallet::address_from_public_key("0x1234").is_err());
+    assert!(EvmWallet::address_from_public_key("zz").is_err());
+    assert!(EvmWallet::address_from_public_key("").is_err());
+    assert!(EvmWallet::address_from_public_key(&format!("05{}", &compressed[2..])).is_err());
+    assert!(EvmWallet::address_from_public_key(&format!("02{}", "ff".repeat(32))).is_err());
+}
+
+#[tokio::test]
+async fn test_sign_and_verify_personal_message() {
+    let wallet = EvmWallet::create_wallet_from_private_key("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80").unwrap();
+    let message = b"hello world";
+    
+    assert_eq!(
+        alloy::primitives::eip191_hash_message(message).to_string(),
+        "0xd9eba16ed0ecae432b71fe008c98cc872bb4cc214d3220a36f365326cf807d68"
+    );
+    
+    let signature = wallet.sign_message(message).await.unwrap();
+    assert_eq!(signature.len(), 132);
+    assert!(signature.ends_with("1b") || signature.ends_with("1c"));
+    
+    let verification = EvmWallet::verify_message(message, &signature, &wallet.address).unwrap();
+    assert!(verification.is_valid);
+    assert_eq!(verification.recovered_address, wallet.address);
+    
+    // 소문자 주소로도 검증 가능
+    let verification = EvmWallet::verify_message(message, &signature, &wallet.address.to_lowercase()).unwrap();
+    assert!(verification.is_valid);
+    
+    let verification = EvmWallet::verify_message(b"hello world!", &signature, &wallet.address).unwrap();
+    assert!(!verification.is_valid);
+    assert_ne!(verification.recovered_address, wallet.address);
+    
+    let other = EvmWallet::new_random().unwrap();
+    let verification = EvmWallet::verify_message(message, &signature, &other.address).unwrap();
+    assert!(!verification.is_valid);
+    assert_eq!(verification.recovered_address, wallet.address);
+    
+    let hex_message = decode_message("0x68656c6c6f20776f726c64", Some("hex")).unwrap();
+    assert_eq!(wallet.sign_message(&hex_message).await.unwrap(), signature);
+    assert!(decode_message("hello", Some("base64")).is_err());
+    
+    assert!(EvmWallet::verify_message(message, "0x1234", &wallet.address).is_err());
+}
+
+#[tokio::test]
+async fn test_sign_and_verify_typed_data() {
+    // EIP-712 스펙 예제 (Mail), 서명 키 = keccak256("cow")
+    let typed_data: alloy::dyn_abi::TypedData = serde_json::from_value(serde_json::json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }
+    })).unwrap();
+    
+    assert_eq!(typed_data.domain.separator().to_string(), "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+    assert_eq!(typed_data.hash_struct().unwrap().to_string(), "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e");
+    assert_eq!(typed_data.eip712_signing_hash().unwrap().to_string(), "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+    
+    let wallet = EvmWallet::create_wallet_from_private_key("c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4").unwrap();
+    assert_eq!(wallet.address, "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826");
+    
+    let signature = wallet.sign_typed_data(&typed_data).await.unwrap();
+    assert_eq!(
+        signature,
+        "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c"
+    );
+    
+    let verification = EvmWallet::verify_typed_data(&typed_data, &signature, &wallet.address).unwrap();
+    assert!(verification.is_valid);
+    assert_eq!(verification.recovered_address, wallet.address);
+    
+    let mut tampered = typed_data.clone();
+    tampered.message["contents"] = serde_json::json!("Hello, Eve!");
+    let verification = EvmWallet::verify_typed_data(&tampered, &signature, &wallet.address).unwrap();
+    assert!(!verification.is_valid);
+    
+    // personal_sign 서명은 typed data 서명으로 인정되지 않음
+    let personal = wallet.sign_message(b"Hello, Bob!").await.unwrap();
+    assert!(!EvmWallet::verify_typed_data(&typed_data, &personal, &wallet.address).unwrap().is_valid);
+    
+    let mut invalid = typed_data.clone();
+    invalid.primary_type = "Unknown".to_string();
+    assert!(wallet.sign_typed_data(&invalid).await.is_err());
+}
+
+#[tokio::test]
+async fn test_offline_transaction_signing() {
+    use alloy::consensus::{transaction::SignerRecoverable, Transaction as _, TxEnvelope};
+    use alloy::eips::Decodable2718;
+    
+    // EIP-155 스펙 예제
+    let wallet = EvmWallet::create_wallet_from_private_key(&"46".repeat(32)).unwrap();
+    let legacy = OfflineTxParams {
+        chain_id: 1,
+        nonce: 9,
+        gas_limit: 21_000,
+        gas_price: Some(20_000_000_000),
+        ..Default::default()
+    };
+    let signed = wallet.sign_native_transaction(
+        "0x3535353535353535353535353535353535353535",
+        parse_raw_amount("1000000000000000000").unwrap(),
+        &legacy,
+    ).await.unwrap();
+    assert_eq!(signed.tx_type, "legacy");
+    assert_eq!(
+        signed.raw_transaction,
+        "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+    );
+    assert_eq!(signed.tx_hash, "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788");
+    
+    let wallet = EvmWallet::create_wallet_from_private_key("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80").unwrap();
+    let eip1559 = OfflineTxParams {
+        chain_id: 137,
+        nonce: 42,
+        gas_limit: 65_000,
+        max_fee_per_gas: Some(50_000_000_000),
+        max_priority_fee_per_gas: Some(30_000_000_000),
+        ..Default::default()
+    };
+    let token = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
+    let recipient = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+    let signed = wallet.sign_erc20_transaction(recipient, U256::from(2_500_000u64), token, &eip1559).await.unwrap();
+    assert_eq!(signed.tx_type, "eip1559");
+    assert_eq!(signed.from, wallet.address);
+    assert_eq!(signed.to, token);
+    
+    let raw = hex::decode(signed.raw_transaction.trim_start_matches("0x")).unwrap();
+    let envelope = TxEnvelope::decode_2718(&mut raw.as_slice()).unwrap();
+    assert_eq!(format!("{:#x}", envelope.tx_hash()), signed.tx_hash);
+    assert_eq!(envelope.recover_signer().unwrap().to_checksum(None), wallet.address);
+    assert_eq!(envelope.chain_id(), Some(137));
+    assert_eq!(envelope.nonce(), 42);
+    assert_eq!(envelope.gas_limit(), 65_000);
+    assert_eq!(envelope.max_fee_per_gas(), 50_000_000_000);
+    assert_eq!(envelope.max_priority_fee_per_gas(), Some(30_000_000_000));
+    assert_eq!(
+        hex::encode(envelope.input()),
+        "a9059cbb00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c800000000000000000000000000000000000000000000000000000000002625a0"
+    );
+    
+    let mixed = OfflineTxParams { gas_price: Some(1), ..eip1559.clone() };
+    assert!(wallet.sign_native_transaction(recipient, U256::from(1), &mixed).await.is_err());
+    let missing_fee = OfflineTxParams { chain_id: 1, gas_limit: 21_000, ..Default::default() };
+    assert!(wallet.sign_native_transaction(recipient, U256::from(1), &missing_fee).await.is_err());
+    let no_chain = OfflineTxParams { chain_id: 0, ..eip1559.clone() };
+    assert!(wallet.sign_native_transaction(recipient, U256::from(1), &no_chain).await.is_err());
+    let inverted = OfflineTxParams { max_priority_fee_per_gas: Some(60_000_000_000), ..eip1559.clone() };
+    assert!(wallet.sign_native_transaction(recipient, U256::from(1), &inverted).await.is_err());
+    
+    assert!(parse_raw_amount("1.5").is_err());
+    assert!(parse_raw_amount("-1").is_err());
+    assert!(parse_raw_amount("").is_err());
+}
+
+#[tokio::test]
+async fn test_decode_raw_transaction() {
+    // EIP-155 스펙 예제 (legacy)
+    let decoded = EvmWallet::decode_raw_transaction(
+        "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+    ).unwrap();
+    assert_eq!(decoded.tx_type, "legacy");
+    assert_eq!(decoded.tx_hash, "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788");
+    assert_eq!(decoded.from, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+    assert_eq!(decoded.to.as_deref(), Some("0x3535353535353535353535353535353535353535"));
+    assert_eq!(decoded.value, "1000000000000000000");
+    assert_eq!(decoded.chain_id, Some(1));
+    assert_eq!(decoded.nonce, 9);
+    assert_eq!(decoded.gas_limit, 21_000);
+    assert_eq!(decoded.gas_price.as_deref(), Some("20000000000"));
+    assert_eq!(decoded.max_fee_per_gas, None);
+    assert_eq!(decoded.max_priority_fee_per_gas, None);
+    assert!(decoded.erc20_transfer.is_none());
+    
+    let wallet = EvmWallet::create_wallet_from_private_key("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80").unwrap();
+    let params = OfflineTxParams {
+        chain_id: 10,
+        nonce: 3,
+        gas_limit: 70_000,
+        max_fee_per_gas: Some(2_000_000_000),
+        max_priority_fee_per_gas: Some(1_000_000),
+        ..Default::default()
+    };
+    let token = "0x0b2C639c533813f4Aa9D7837CAf62653d097Ff85";
+    let recipient = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+    let signed = wallet.sign_erc20_transaction(recipient, U256::from(7_000_000u64), token, &params).await.unwrap();
+    
+    let decoded = EvmWallet::decode_raw_transaction(&signed.raw_transaction).unwrap();
+    assert_eq!(decoded.tx_type, "eip1559");
+    assert_eq!(decoded.tx_hash, signed.tx_hash);
+    assert_eq!(decoded.from, wallet.address);
+    assert_eq!(decoded.to.as_deref(), Some(token));
+    assert_eq!(decoded.value, "0");
+    assert_eq!(decoded.chain_id, Some(10));
+    assert_eq!(decoded.nonce, 3);
+    assert_eq!(decoded.gas_price, None);
+    assert_eq!(decoded.max_fee_per_gas.as_deref(), Some("2000000000"));
+    assert_eq!(decoded.max_priority_fee_per_gas.as_deref(), Some("1000000"));
+    let transfer = decoded.erc20_transfer.unwrap();
+    assert_eq!(transfer.token_address, token);
+    assert_eq!(transfer.to, recipient);
+    assert_eq!(transfer.amount, "7000000");
+    
+    assert!(EvmWallet::decode_raw_transaction("0xzz").is_err());
+    assert!(EvmWallet::decode_raw_transaction(&signed.raw_transaction[..signed.raw_transaction.len() - 2]).is_err());
+    assert!(EvmWallet::decode_raw_transaction(&format!("{}00", signed.raw_transaction)).is_err());
+}
+
+#[tokio::test]
+async fn test_transaction_type_selection() {
+    use std::str::FromStr;
+    
+    assert_eq!(TransactionType::from_str("legacy").unwrap(), TransactionType::Legacy);
+    assert_eq!(TransactionType::from_str("EIP2930").unwrap(), TransactionType::Eip2930);
+    assert_eq!(TransactionType::from_str("2").unwrap(), TransactionType::Eip1559);
+    assert!(TransactionType::from_str("eip4844").is_err());
+    let parsed: TransactionType = serde_json::from_value(serde_json::json!("eip1559")).unwrap();
+    assert_eq!(parsed, TransactionType::Eip1559);
+    let parsed: TransactionType = serde_json::from_value(serde_json::json!(2)).unwrap();
+    assert_eq!(parsed, TransactionType::Eip1559);
+    let parsed: TransactionType = serde_json::from_value(serde_json::json!(0)).unwrap();
+    assert_eq!(parsed, TransactionType::Legacy);
+    assert!(serde_json::from_value::<TransactionType>(serde_json::json!(3)).is_err());
+    
+    let fees = (U256::from(40u64), U256::from(2u64), U256::from(21u64));
+    let tx = alloy::rpc::types::TransactionRequest::default();
+    let legacy = apply_fee_fields(tx.clone(), TransactionType::Legacy, fees.0, fees.1, fees.2).unwrap();
+    assert_eq!((legacy.transaction_type, legacy.gas_price, legacy.max_fee_per_gas), (Some(0), Some(21), None));
+    let eip2930 = apply_fee_fields(tx.clone(), TransactionType::Eip2930, fees.0, fees.1, fees.2).unwrap();
+    assert_eq!((eip2930.transaction_type, eip2930.gas_price), (Some(1), Some(21)));
+    assert!(eip2930.access_list.is_some());
+    let eip1559 = apply_fee_fields(tx.clone(), TransactionType::Eip1559, fees.0, fees.1, fees.2).unwrap();
+    assert_eq!(eip1559.gas_price, None);
+    assert_eq!((eip1559.max_fee_per_gas, eip1559.max_priority_fee_per_gas), (Some(40), Some(2)));
+    // 2 * u128::MAX 같은 RPC 유래 값은 panic 대신 에러
+    let too_large = U256::from(u128::MAX) * U256::from(2u64);
+    assert!(apply_fee_fields(tx.clone(), TransactionType::Eip1559, too_large, fees.1, fees.2).unwrap_err().to_string().contains("max_fee_per_gas"));
+    assert!(apply_fee_fields(tx, TransactionType::Legacy, fees.0, fees.1, too_large).unwrap_err().to_string().contains("gas_price"));
+    assert_eq!(effective_gas_price(TransactionType::Legacy, fees.2, fees.1), U256::from(21u64));
+    assert_eq!(effective_gas_price(TransactionType::Eip1559, fees.2, fees.1), U256::from(23u64));
+    
+    // 요청 값 > TX_TYPE > 자동 판별 (RPC 실패 시 EIP-1559)
+    let unreachable = "http://127.0.0.1:1";
+    env::set_var("TX_TYPE", "legacy");
+    assert_eq!(resolve_transaction_type(Some(TransactionType::Eip2930), unreachable).await, TransactionType::Eip2930);
+    assert_eq!(resolve_transaction_type(None, unreachable).await, TransactionType::Legacy);
+    env::remove_var("TX_TYPE");
+    assert_eq!(resolve_transaction_type(None, unreachable).await, TransactionType::Eip1559);
+    
+    let wallet = EvmWallet::create_wallet_from_private_key("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80").unwrap();
+    let params = OfflineTxParams {
+        chain_id: 56,
+        nonce: 1,
+        gas_limit: 21_000,
+        gas_price: Some(3_000_000_000),
+        tx_type: Some(TransactionType::Eip2930),
+        ..Default::default()
+    };
+    let signed = wallet.sign_native_transaction("0x70997970C51812dc3A010C7d01b50e0d17dc79C8", U256::from(1u64), &params).await.unwrap();
+    assert_eq!(signed.tx_type, "eip2930");
+    assert!(signed.raw_transaction.starts_with("0x01"));
+    let decoded = EvmWallet::decode_raw_transaction(&signed.raw_transaction).unwrap();
+    assert_eq!(decoded.tx_type, "eip2930");
+    assert_eq!(decoded.gas_price.as_deref(), Some("3000000000"));
+    
+    let mismatched = OfflineTxParams { tx_type: Some(TransactionType::Eip1559), ..params };
+    assert!(wallet.sign_native_transaction("0x70997970C51812dc3A010C7d01b50e0d17dc79C8", U256::from(1u64), &mismatched).await.is_err());
+}
+
+#[tokio::test]
+async fn test_signer_backends_produce_identical_signatures() {
+    use alloy::consensus::{SignableTransaction, TxEip1559};
+    use alloy::network::TxSigner;
+    use std::sync::Arc;
+    
+    let private_key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    let local = EvmWallet::create_wallet_from_private_key(private_key).unwrap();
+    
+    let keystore = encrypt_key(&hex::decode(private_key).unwrap(), "pw", KeystoreKdf::Pbkdf2 { c: 1000 }).unwrap();
+    assert!(KeystoreSigner::new(keystore.clone(), SecretString::from("wrong")).is_err());
+    let keystore_wallet = EvmWallet::from_signer(Arc::new(KeystoreSigner::new(keystore, SecretString::from("pw")).unwrap()));
+    
+    let url = spawn_remote_signer(private_key).await;
+    let remote_wallet = EvmWallet::from_signer(Arc::new(RemoteSigner::new(&url, &local.address, None).unwrap()));
+    assert!(WalletResponse::try_from(EvmWallet::from_signer(Arc::new(RemoteSigner::new(&url, &local.address, None).unwrap()))).is_err());
+    assert!(WalletResponse::try_from(EvmWallet::create_wallet_from_private_key(private_key).unwrap()).is_ok());
+    
+    let params = OfflineTxParams {
+        chain_id: 1,
+        nonce: 0,
+        gas_limit: 21_000,
+        max_fee_per_gas: Some(20_000_000_000),
+        max_priority_fee_per_gas: Some(1_000_000_000),
+        ..Default::default()
+    };
+    let expected_message = local.sign_message(b"login").await.unwrap();
+    let expected_tx = local.sign_native_transaction("0x70997970C51812dc3A010C7d01b50e0d17dc79C8", U256::from(1u64), &params).await.unwrap();
+    for wallet in [&keystore_wallet, &remote_wallet] {
+        assert_eq!(wallet.address, local.address);
+        assert!(wallet.private_key_hex().unwrap_err().to_string().contains("not available for this signer backend"));
+        assert!(wallet.compressed_public_key().is_err());
+        assert!(wallet.encrypt_keystore("pw", KeystoreKdf::Pbkdf2 { c: 1000 }).is_err());
+        assert_eq!(wallet.sign_message(b"login").await.unwrap(), expected_message);
+        let signed = wallet.sign_native_transaction("0x70997970C51812dc3A010C7d01b50e0d17dc79C8", U256::from(1u64), &params).await.unwrap();
+        assert_eq!(signed.raw_transaction, expected_tx.raw_transaction);
+    }
+    
+    // provider 경로에서 쓰는 TxSigner 어댑터
+    let adapter = BackendTxSigner(remote_wallet.signer().unwrap().clone());
+    let mut tx = TxEip1559 { chain_id: 1, gas_limit: 21_000, ..Default::default() };
+    let signature = adapter.sign_transaction(&mut tx).await.unwrap();
+    assert_eq!(signature.recover_address_from_prehash(&tx.signature_hash()).unwrap(), adapter.address());
+    
+    // 다른 키로 서명하는 원격 서명자는 거부
+    let other = EvmWallet::new_random().unwrap();
+    let impostor = EvmWallet::from_signer(Arc::new(RemoteSigner::new(&url, &other.address, None).unwrap()));
+    assert!(impostor.sign_message(b"login").await.unwrap_err().to_string().contains("instead of"));
+    let unreachable = EvmWallet::from_signer(Arc::new(RemoteSigner::new("http://127.0.0.1:1/sign", &local.address, None).unwrap()));
+    assert!(unreachable.sign_message(b"login").await.is_err());
+}
+
+#[tokio::test]
+async fn test_wallet_without_signer_returns_error() {
+    let wallet = EvmWallet::new_random().unwrap();
+    let restored: EvmWallet = serde_json::from_str(&serde_json::to_string(&wallet).unwrap()).unwrap();
+    assert!(restored.signer.is_none());
+    
+    let recipient = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+    let error = restored.send_native_coin(recipient, U256::from(1u64), "http://127.0.0.1:1").await.unwrap_err();
+    assert!(error.to_string().contains("no signer"));
+    assert!(restored.estimate_gas(recipient, U256::from(1u64), "http://127.0.0.1:1").await.is_err());
+    assert!(restored.sign_message(b"hello").await.is_err());
+    assert!(restored.sign_native_transaction(recipient, U256::from(1u64), &OfflineTxParams::default()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_exact_decimal_amount_parsing() {
+    assert_eq!(parse_decimal_amount("1", 18).unwrap(), U256::from(1_000_000_000_000_000_000u128));
+    assert_eq!(parse_decimal_amount("0.1", 18).unwrap(), U256::from(100_000_000_000_000_000u128));
+    // f64 로는 0.30000000000000004 가 되는 값
+    assert_eq!(parse_decimal_amount("0.3", 18).unwrap(), U256::from(300_000_000_000_000_000u128));
+    assert_eq!(parse_decimal_amount("123456789.123456789123456789", 18).unwrap(), U256::from_str_radix("123456789123456789123456789", 10).unwrap());
+    assert_eq!(parse_decimal_amount("1.5", 6).unwrap(), U256::from(1_500_000u64));
+    assert_eq!(parse_decimal_amount("0.000001", 6).unwrap(), U256::from(1u64));
+    assert_eq!(parse_decimal_amount(" 42 ", 0).unwrap(), U256::from(42u64));
+    assert_eq!(parse_decimal_amount("0", 77).unwrap(), U256::ZERO);
+    assert_eq!(parse_decimal_amount("1", 50).unwrap(), U256::from(10u64).pow(U256::from(50)));
+    
+    assert!(parse_decimal_amount("-1", 18).unwrap_err().to_string().contains("negative"));
+    assert!(parse_decimal_amount("1.0000001", 6).unwrap_err().to_string().contains("fractional digits"));
+    assert!(parse_decimal_amount("1", 78).unwrap_err().to_string().contains("too large"));
+    assert!(parse_decimal_amount("115792089237316195423570985008687907853269984665640564039458", 18).unwrap_err().to_string().contains("too large"));
+    for invalid in ["", ".5", "1.", "1e18", "0x10", "1,5", "1.2.3", "abc"] {
+        assert!(parse_decimal_amount(invalid, 18).is_err(), "{} should be rejected", invalid);
+    }
+    
+    let max = U256::MAX.to_string();
+    assert_eq!(parse_raw_amount(&max).unwrap(), U256::MAX);
+    assert!(parse_raw_amount(&format!("{}0", max)).is_err());
+    
+    // JSON 정수는 정확하므로 허용, 소수 JSON 숫자는 f64로 반올림되므로 거부
+    let request: SendTransactionRequest = serde_json::from_value(serde_json::json!({
+        "to": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+        "amount": 2
+    })).unwrap();
+    assert_eq!(request.amount, Some(AmountInput::Decimal("2".to_string())));
+    let request: SendTransactionRequest = serde_json::from_str(
+        r#"{"to": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8", "amount": 0.1234567890123456789}"#
+    ).unwrap();
+    assert!(request.amount.as_ref().unwrap().as_decimal().unwrap_err().to_string().contains("decimal string"));
+    let request: SendTransactionRequest = serde_json::from_str(
+        r#"{"to": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8", "amount": "0.1234567890123456789"}"#
+    ).unwrap();
+    let exact = request.amount.as_ref().unwrap().as_decimal().unwrap();
+    assert_eq!(parse_decimal_amount(exact, 19).unwrap(), U256::from(1_234_567_890_123_456_789u64));
+    let request: SendErc20Request = serde_json::from_value(serde_json::json!({
+        "to": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+        "amount_raw": "2500000",
+        "token_address": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+    })).unwrap();
+    assert_eq!(request.amount, None);
+    assert_eq!(request.amount_raw.as_deref(), Some("2500000"));
+}
+
+#[tokio::test]
+async fn test_nonce_manager_allocation() {
+    let manager = NonceManager::new();
+    let address = EvmWallet::new_random().unwrap().signer().unwrap().address();
+    let rpc_url = "http://localhost:8545";
+
+    // The node lags behind: locally committed nonces win over a stale pending count.
+    let mut lease = manager.lease(rpc_url, address).await;
+    assert_eq!(lease.allocate(5), 5);
+    lease.commit(5);
+    assert_eq!(lease.allocate(5), 6);
+    assert_eq!(lease.allocate(9), 9);
+    drop(lease);
+
+    // "nonce too low": the rejected nonce is never handed out again.
+    let mut lease = manager.lease(rpc_url, address).await;
+    assert_eq!(lease.resync(6, 6), 7);
+    assert_eq!(lease.resync(12, 7), 12);
+    lease.invalidate();
+    assert_eq!(lease.cached(), None);
+    assert_eq!(lease.allocate(3), 3);
+    drop(lease);
+
+    // Concurrent callers are serialized and each sees the previous commit.
+    let manager = std::sync::Arc::new(manager);
+    let tasks = (0..16).map(|_| {
+        let manager = manager.clone();
+        tokio::spawn(async move {
+            let mut lease = manager.lease(rpc_url, address).await;
+            let nonce = lease.allocate(0);
+            tokio::task::yield_now().await;
+            lease.commit(nonce);
+            nonce
+        })
+    });
+    let mut nonces: Vec<u64> = futures::future::join_all(tasks).await.into_iter().map(|r| r.unwrap()).collect();
+    nonces.sort();
+    assert_eq!(nonces, (0..16).collect::<Vec<u64>>());
+
+    // Other addresses and endpoints are tracked independently.
+    let other = EvmWallet::new_random().unwrap().signer().unwrap().address();
+    assert_eq!(manager.lease(rpc_url, other).await.cached(), None);
+    assert_eq!(manager.lease("http://localhost:8546", address).await.cached(), None);
+
+    // Idle slots with nothing tracked are dropped on the next lease; the committed slot and the newest one remain.
+    for port in 9000..9010 {
+        drop(manager.lease(&format!("http://localhost:{}", port), address).await);
+    }
+    assert_eq!(manager.slot_count(), 2);
+
+    assert!(is_nonce_conflict("server returned an error response: error code -32000: nonce too low"));
+    assert!(is_nonce_conflict("replacement transaction underpriced"));
+    assert!(!is_nonce_conflict("already known"));
+    assert!(!is_nonce_conflict("insufficient funds for gas * price + value"));
+}
+
+#[tokio::test]
+async fn test_wait_for_confirmations_times_out_on_unreachable_rpc() {
+    let hash = alloy::primitives::TxHash::repeat_byte(0x11);
+    let started = std::time::Instant::now();
+    let result = EvmWallet::wait_for_confirmations(hash, 1, std::time::Duration::from_secs(1), "http://127.0.0.1:1").await;
+    // Polling errors are retried until the timeout instead of failing the wait.
+    assert!(result.unwrap().is_none());
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_replacement_fee_bump() {
+    assert_eq!(bump_replacement_fee(8_000_000_000), 9_000_000_000);
+    // Rounded up, so the 12.5% minimum always holds.
+    assert_eq!(bump_replacement_fee(1_000_000_001), 1_125_000_002);
+    assert_eq!(bump_replacement_fee(0), 1);
+    assert_eq!(bump_replacement_fee(1), 2);
+    assert_eq!(bump_replacement_fee(u128::MAX), u128::MAX);
+    assert_eq!(ReplacementKind::SpeedUp.as_str(), "speed_up");
+    assert_eq!(ReplacementKind::Cancel.as_str(), "cancel");
+}
+
+#[tokio::test]
+async fn test_fee_override_validation() {
+    let caps = FeeCaps {
+        max_gas_limit: 1_000_000,
+        max_fee_per_gas: 200_000_000_000,
+        max_priority_fee_per_gas: 5_000_000_000,
+        max_gas_price: 100_000_000_000,
+    };
+    let valid = [
+        TxOptions::default(),
+        TxOptions { gas_limit: Some(21_000), gas_price: Some(100_000_000_000), ..Default::default() },
+        TxOptions { max_fee_per_gas: Some(50_000_000_000), max_priority_fee_per_gas: Some(2_000_000_000), ..Default::default() },
+        TxOptions { tx_type: Some(TransactionType::Eip2930), gas_price: Some(1), nonce: Some(7), ..Default::default() },
+    ];
+    for options in &valid {
+        assert!(validate_tx_options(options, &caps).is_ok(), "{:?}", options);
+    }
+
+    let invalid = [
+        (TxOptions { gas_limit: Some(20_999), ..Default::default() }, "gas_limit"),
+        (TxOptions { gas_limit: Some(1_000_001), ..Default::default() }, "MAX_GAS_LIMIT"),
+        (TxOptions { max_fee_per_gas: Some(200_000_000_001), ..Default::default() }, "MAX_FEE_PER_GAS_WEI"),
+        (TxOptions { max_priority_fee_per_gas: Some(5_000_000_001), ..Default::default() }, "MAX_PRIORITY_FEE_PER_GAS_WEI"),
+        (TxOptions { gas_price: Some(100_000_000_001), ..Default::default() }, "MAX_GAS_PRICE_WEI"),
+        (TxOptions { gas_price: Some(0), ..Default::default() }, "gas_price"),
+        (TxOptions { max_fee_per_gas: Some(1_000), max_priority_fee_per_gas: Some(1_001), ..Default::default() }, "must not exceed"),
+        (TxOptions { gas_price: Some(1), max_fee_per_gas: Some(1), ..Default::default() }, "cannot be combined"),
+        (TxOptions { tx_type: Some(TransactionType::Eip1559), gas_price: Some(1), ..Default::default() }, "not gas_price"),
+        (TxOptions { tx_type: Some(TransactionType::Legacy), max_priority_fee_per_gas: Some(1), ..Default::default() }, "take gas_price"),
+    ];
+    for (options, expected) in &invalid {
+        let error = validate_tx_options(options, &caps).unwrap_err().to_string();
+        assert!(error.contains(expected), "{:?}: {}", options, error);
+    }
+
+    // Fee overrides imply a transaction type unless one is requested.
+    assert_eq!(TxOptions::default().requested_transaction_type(), None);
+    assert_eq!(TxOptions { gas_price: Some(1), ..Default::default() }.requested_transaction_type(), Some(TransactionType::Legacy));
+    assert_eq!(TxOptions { max_fee_per_gas: Some(1), ..Default::default() }.requested_transaction_type(), Some(TransactionType::Eip1559));
+    assert_eq!(
+        TxOptions { tx_type: Some(TransactionType::Eip2930), gas_price: Some(1), ..Default::default() }.requested_transaction_type(),
+        Some(TransactionType::Eip2930)
+    );
+
+    env::set_var("MAX_GAS_PRICE_WEI", "50000000000");
+    env::set_var("MAX_PRIORITY_FEE_PER_GAS_WEI", "not-a-number");
+    let configured = configured_fee_caps();
+    assert_eq!(configured.max_gas_price, 50_000_000_000);
+    assert_eq!(configured.max_priority_fee_per_gas, DEFAULT_MAX_FEE_WEI);
+    assert_eq!(configured.max_gas_limit, DEFAULT_MAX_GAS_LIMIT);
+    env::remove_var("MAX_GAS_PRICE_WEI");
+    env::remove_var("MAX_PRIORITY_FEE_PER_GAS_WEI");
+}
+
+#[tokio::test]
+async fn test_vault_bearer_token_check() {
+    let token = "s3cr3t-operator-token";
+    assert!(bearer_token_matches(token, Some("Bearer s3cr3t-operator-token")));
+    assert!(!bearer_token_matches(token, None));
+    assert!(!bearer_token_matches(token, Some("s3cr3t-operator-token")));
+    assert!(!bearer_token_matches(token, Some("Bearer s3cr3t-operator-toke")));
+    assert!(!bearer_token_matches(token, Some("Bearer s3cr3t-operator-tokem")));
+    assert!(!bearer_token_matches("", Some("Bearer ")));
+    // No token is configured outside main(), so vault access is always refused.
+    assert!(!vault_token_authorized(Some("Bearer s3cr3t-operator-token")));
+}
+
+#[tokio::test]
+async fn test_out_of_range_rpc_fee_is_rejected() {
+    use axum::{routing::post, Json, Router};
+    
+    // eth_gasPrice = u128::MAX이면 기본 max fee(2배)가 u128을 넘음 → panic 없이 에러
+    let app = Router::new().route("/", post(|Json(request): Json<serde_json::Value>| async move {
+        let result = match request["method"].as_str() {
+            Some("eth_gasPrice") => format!("{:#x}", u128::MAX),
+            _ => "0x1".to_string(),
+        };
+        Json(serde_json::json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }))
+    }));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let rpc_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    
+    let wallet = EvmWallet::new_random().unwrap();
+    let options = TxOptions {
+        tx_type: Some(TransactionType::Eip1559),
+        gas_limit: Some(21_000),
+        ..Default::default()
+    };
+    let result = wallet.send_native_coin_with_options("0x70997970C51812dc3A010C7d01b50e0d17dc79C8", U256::from(1u64), &rpc_url, &options).await;
+    assert!(result.unwrap_err().to_string().contains("max_fee_per_gas"));
+}
+
+#[tokio::test]
+async fn test_mnemonic_legacy_derivation() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    
+    let legacy_wallet = EvmWallet::from_mnemonic_legacy(mnemonic, "").unwrap();
+    let bip44_wallet = EvmWallet::from_mnemonic(mnemonic).unwrap();
+    
+    assert_ne!(legacy_wallet.address, bip44_wallet.address);
+    assert_eq!(legacy_wallet.derivation_path, None);
+    assert_eq!(legacy_wallet.private_key.expose_secret(), "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc1");
+}
+
 #[tokio::test]
 async fn test_transaction_functions_with_known_private_key() {
     let anvil = Anvil::new().spawn();
@@ -214,11 +1398,11 @@ async fn test_transaction_functions_with_known_private_key() {
     let test_private_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
     
     let address = EvmWallet::address_from_private_key(test_private_key).unwrap();
-    assert_eq!(address, "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+    assert_eq!(address, "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
     
     let wallet = EvmWallet::create_wallet_from_private_key(test_private_key).unwrap();
     assert_eq!(wallet.address, address);
-    assert_eq!(wallet.private_key, test_private_key);
+    assert_eq!(wallet.private_key.expose_secret(), test_private_key);
     
     let dummy_recipient = "0x742d35Cc6634C0532925a3b8D55de0c4a2e6D6b4";
     let amount = U256::from(1000000000000000000u64);
@@ -433,8 +1617,6 @@ async fn test_token_amount_conversion() {
 
 #[tokio::test]
 async fn test_multiple_network_scenarios() {
-    use evm_wallet::utils::get_smart_gas_price;
-    
     // 다양한 네트워크 URL 패턴 테스트
     let network_urls = vec![
         ("Ethereum", "https://eth-mainnet.infura.io"),